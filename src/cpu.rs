@@ -1,7 +1,95 @@
-use crate::memory::Memory;
-use crate::{instructions, Byte, Word};
+use crate::memory::Bus;
+use crate::opcodes;
+use crate::{Byte, Word};
+use std::fmt;
+
+const NMI_VECTOR: Word = 0xFFFA;
+const IRQ_VECTOR: Word = 0xFFFE;
+
+// ANE and LXA mix in chip-dependent bits of A; $EE is the value most parts show
+const UNSTABLE_MAGIC: Byte = 0xEE;
+
+#[allow(unused)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuModel {
+    Nmos6502,
+    Ricoh2A03, // NES: NMOS core with the decimal adder disconnected
+    Mos6507,   // Atari 2600: NMOS core with only 13 address lines
+    Cmos65C02, // WDC/Rockwell, with the bit instructions and WAI/STP
+    Cmos65SC02,
+}
+
+#[allow(unused)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IllegalOpcodes {
+    Undocumented, // the NMOS decode matrix side effects (LAX, SAX, ...)
+    Nop,          // CMOS parts turn every undefined opcode into a NOP
+}
+
+// Everything that differs between the chips a CpuModel stands for
+#[allow(non_snake_case, unused)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quirks {
+    pub JmpIndirectPageBug: bool,
+    pub DecimalMode: bool,
+    pub IllegalOpcodes: IllegalOpcodes,
+    // CMOS: valid decimal N/Z, D cleared on interrupt, RMW reads twice instead of writing twice
+    pub Cmos: bool,
+    pub BitInstructions: bool, // RMB/SMB/BBR/BBS and WAI/STP
+    pub AddressMask: Word,
+    pub ResetSP: Byte,
+    pub ResetStatus: Byte,
+}
+
+#[allow(unused)]
+impl CpuModel {
+    pub fn quirks(&self) -> Quirks {
+        match self {
+            // NMOS leaves SP and P undefined after reset, they start out cleared
+            CpuModel::Nmos6502 => Quirks {
+                JmpIndirectPageBug: true,
+                DecimalMode: true,
+                IllegalOpcodes: IllegalOpcodes::Undocumented,
+                Cmos: false,
+                BitInstructions: false,
+                AddressMask: 0xFFFF,
+                ResetSP: 0xFF,
+                ResetStatus: 0x00,
+            },
+            // SP and P as found after power-up by the usual NES test ROMs
+            CpuModel::Ricoh2A03 => Quirks {
+                DecimalMode: false,
+                ResetSP: 0xFD,
+                ResetStatus: 0b0000_0100,
+                ..CpuModel::Nmos6502.quirks()
+            },
+            CpuModel::Mos6507 => Quirks {
+                AddressMask: 0x1FFF,
+                ..CpuModel::Nmos6502.quirks()
+            },
+            // CMOS parts set I and clear D on reset
+            CpuModel::Cmos65C02 => Quirks {
+                JmpIndirectPageBug: false,
+                DecimalMode: true,
+                IllegalOpcodes: IllegalOpcodes::Nop,
+                Cmos: true,
+                BitInstructions: true,
+                AddressMask: 0xFFFF,
+                ResetSP: 0xFF,
+                ResetStatus: 0b0000_0100,
+            },
+            CpuModel::Cmos65SC02 => Quirks {
+                BitInstructions: false,
+                ..CpuModel::Cmos65C02.quirks()
+            },
+        }
+    }
+}
+
+const INTERRUPT_CYCLES: Byte = 7;
 
 #[allow(non_snake_case, unused)]
+#[derive(Clone)]
 pub struct Flags {
     pub Carry: bool,
     pub Zero: bool,
@@ -12,7 +100,111 @@ pub struct Flags {
     pub Negative: bool,
 }
 
+#[allow(unused)]
+impl Flags {
+    // NV1B DIZC
+    pub fn to_byte(&self, brk: bool) -> Byte {
+        (self.Negative as Byte) << 7
+            | (self.Overflow as Byte) << 6
+            | 1 << 5
+            | (brk as Byte) << 4
+            | (self.DecimalMode as Byte) << 3
+            | (self.InterruptDisable as Byte) << 2
+            | (self.Zero as Byte) << 1
+            | self.Carry as Byte
+    }
+
+    // B and bit 5 only exist on the stack, so they are ignored when pulled
+    pub fn set_from_byte(&mut self, value: Byte) {
+        self.Negative = (value & 0b1000_0000) > 0;
+        self.Overflow = (value & 0b0100_0000) > 0;
+        self.DecimalMode = (value & 0b0000_1000) > 0;
+        self.InterruptDisable = (value & 0b0000_0100) > 0;
+        self.Zero = (value & 0b0000_0010) > 0;
+        self.Carry = (value & 0b0000_0001) > 0;
+    }
+}
+
+// What an execute call ran
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StepInfo {
+    pub PC: Word,             // address the instruction was fetched from
+    pub Opcode: Option<Byte>, // None for interrupt sequences and idle WAI/STP cycles
+    pub Cycles: Byte,
+}
+
 #[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuError {
+    Jammed { Opcode: Byte, PC: Word },
+}
+
+impl fmt::Display for CpuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CpuError::Jammed { Opcode, PC } => {
+                write!(f, "CPU jammed by opcode {:02X} at {:04X}", Opcode, PC)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CpuError {}
+
+// What the CPU does on the bus during a single clock cycle
+#[allow(unused)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BusCycle {
+    Read(Word, Byte),
+    Write(Word, Byte),
+    Idle, // WAI/STP, and cycles the CMOS parts spend internally
+}
+
+// Bus accesses of an instruction being ticked through. The ones before `target`
+// already reached memory and are replayed from here, the one at `target` goes to
+// memory now and anything after it waits for a later tick.
+#[derive(Clone)]
+struct BusTrace {
+    accesses: Vec<BusCycle>,
+    target: usize,
+    position: usize,
+}
+
+impl BusTrace {
+    fn read<B: Bus + ?Sized>(&mut self, mem: &mut B, address: Word) -> Byte {
+        let position = self.position;
+        self.position += 1;
+        match self.accesses.get(position) {
+            Some(BusCycle::Read(_, value)) => *value,
+            None if position == self.target => {
+                let value = mem.read(address);
+                self.accesses.push(BusCycle::Read(address, value));
+                value
+            }
+            _ => 0,
+        }
+    }
+
+    fn write<B: Bus + ?Sized>(&mut self, mem: &mut B, address: Word, value: Byte) {
+        let position = self.position;
+        self.position += 1;
+        if position == self.target {
+            mem.write(address, value);
+            self.accesses.push(BusCycle::Write(address, value));
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TickState {
+    start: CPU,
+    accesses: Vec<BusCycle>,
+    cycle: usize,
+}
+
+#[allow(non_snake_case, unused, clippy::upper_case_acronyms)]
+#[derive(Clone)]
 pub struct CPU {
     pub PC: Word, // Program Counter
     pub SP: Byte, // Stack Pointer
@@ -24,11 +216,46 @@ pub struct CPU {
 
     // Status Register
     pub Status: Flags,
+
+    // Set together by with_model, so the quirks always match the model
+    model: CpuModel,
+    quirks: Quirks,
+    // Scratch for step(), summed into StepInfo.Cycles on top of the base timing
+    extra_cycles: Byte,
+    // Cycles spent since power-up
+    pub Cycles: u64,
+
+    // Interrupt lines
+    irq_line: bool,
+    nmi_line: bool,
+    nmi_pending: bool,
+
+    // WAI parks the CPU until an interrupt arrives, STP until reset
+    waiting: bool,
+    halted: bool,
+    // set by the NMOS JAM opcodes, only reset clears it
+    jam: Option<CpuError>,
+    // the opcode being executed, for handlers shared by several opcodes
+    opcode: Byte,
+
+    // Cycle stepping: the instruction in flight and the replay of its bus accesses
+    tick_state: Option<Box<TickState>>,
+    trace: Option<BusTrace>,
+}
+
+impl Default for CPU {
+    fn default() -> CPU {
+        CPU::new()
+    }
 }
 
 #[allow(non_snake_case, unused)]
 impl CPU {
     pub fn new() -> CPU {
+        CPU::with_model(CpuModel::Nmos6502)
+    }
+
+    pub fn with_model(model: CpuModel) -> CPU {
         CPU {
             PC: 0,
             SP: 0,
@@ -44,12 +271,33 @@ impl CPU {
                 Overflow: false,
                 Negative: false,
             },
+            model,
+            quirks: model.quirks(),
+            extra_cycles: 0,
+            Cycles: 0,
+            irq_line: false,
+            nmi_line: false,
+            nmi_pending: false,
+            waiting: false,
+            halted: false,
+            jam: None,
+            opcode: 0,
+            tick_state: None,
+            trace: None,
         }
     }
 
+    pub fn model(&self) -> CpuModel {
+        self.model
+    }
+
+    pub fn quirks(&self) -> &Quirks {
+        &self.quirks
+    }
+
     pub fn reset(&mut self) {
         self.PC = 0xFFFC;
-        self.SP = 0xFF;
+        self.SP = self.quirks.ResetSP;
         self.Status = Flags {
             Carry: false,
             Zero: false,
@@ -59,60 +307,251 @@ impl CPU {
             Overflow: false,
             Negative: false,
         };
+        self.Status.set_from_byte(self.quirks.ResetStatus);
+        self.nmi_pending = false;
+        self.waiting = false;
+        self.halted = false;
+        self.jam = None;
+        self.tick_state = None;
     }
 
-    fn fetch_byte(&mut self, mem: &Memory) -> Byte {
-        let data = mem.data[self.PC as usize];
-        self.PC += 1;
+    // IRQ is level triggered: it is serviced for as long as the line is held
+    pub fn set_irq(&mut self, asserted: bool) {
+        self.irq_line = asserted;
+    }
+
+    // NMI is edge triggered: only a low-to-high transition raises it
+    pub fn set_nmi(&mut self, asserted: bool) {
+        if asserted && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = asserted;
+    }
+
+    fn fetch_byte<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Byte {
+        // PC rolls over from $FFFF to $0000
+        let data = self.read_byte(mem, self.PC);
+        self.PC = self.PC.wrapping_add(1);
         data
     }
 
-    fn read_byte(&mut self, mem: &Memory, address: Word) -> Byte {
-        
-        mem.data[address as usize]
+    fn read_byte<B: Bus + ?Sized>(&mut self, mem: &mut B, address: Word) -> Byte {
+        let address = address & self.quirks.AddressMask;
+        match self.trace.as_mut() {
+            Some(trace) => trace.read(mem, address),
+            None => mem.read(address),
+        }
+    }
+
+    fn write_byte<B: Bus + ?Sized>(&mut self, mem: &mut B, address: Word, value: Byte) {
+        let address = address & self.quirks.AddressMask;
+        match self.trace.as_mut() {
+            Some(trace) => trace.write(mem, address, value),
+            None => mem.write(address, value),
+        }
+    }
+
+    fn push_byte<B: Bus + ?Sized>(&mut self, mem: &mut B, value: Byte) {
+        // the stack lives in page $01 and wraps within it
+        self.write_byte(mem, 0x0100 | self.SP as Word, value);
+        self.SP = self.SP.wrapping_sub(1);
+    }
+
+    fn pull_byte<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Byte {
+        self.SP = self.SP.wrapping_add(1);
+        self.read_byte(mem, 0x0100 | self.SP as Word)
+    }
+
+    // Reads the stack without moving SP, the pulls spend a cycle on this before incrementing
+    fn peek_stack<B: Bus + ?Sized>(&mut self, mem: &mut B) {
+        self.read_byte(mem, 0x0100 | self.SP as Word);
+    }
+
+    fn push_word<B: Bus + ?Sized>(&mut self, mem: &mut B, value: Word) {
+        self.push_byte(mem, (value >> 8) as Byte);
+        self.push_byte(mem, value as Byte);
+    }
+
+    fn pull_word<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
+        let lo = self.pull_byte(mem);
+        let hi = self.pull_byte(mem);
+        ((hi as u16) << 8) | lo as u16
     }
 
-    fn fetch_word(&mut self, mem: &Memory) -> Word {
+    fn fetch_word<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
         // little endian
-        let lo = mem.data[self.PC as usize];
-        let hi = mem.data[(self.PC + 1) as usize];
-        let data = ((hi as u16) << 8) | lo as u16;
-        self.PC += 2;
-        data
+        let lo = self.fetch_byte(mem);
+        let hi = self.fetch_byte(mem);
+        ((hi as u16) << 8) | lo as u16
     }
 
-    pub fn execute(&mut self, mem: &mut Memory) {
-        let opcode = self.fetch_byte(mem);
-        match opcode {
-            instructions::LDA::IMM => {
-                self.handle_LDA_IMM(mem);
+    // Runs one instruction (or interrupt sequence). A jammed CPU keeps failing until reset.
+    pub fn execute<B: Bus>(&mut self, mem: &mut B) -> Result<StepInfo, CpuError> {
+        // an instruction left half way through by tick() is finished first
+        while self.tick_state.is_some() {
+            if let (_, Some(step)) = self.tick_step(mem)? {
+                return Ok(step);
+            }
+        }
+        if let Some(error) = self.jam {
+            return Err(error);
+        }
+
+        let pc = self.PC;
+        self.extra_cycles = 0;
+        let (opcode, base_cycles) = self.step(mem);
+        if let Some(error) = self.jam {
+            return Err(error);
+        }
+        let cycles = base_cycles + self.extra_cycles;
+        self.Cycles += cycles as u64;
+        Ok(StepInfo {
+            PC: pc,
+            Opcode: opcode,
+            Cycles: cycles,
+        })
+    }
+
+    // Advances one clock cycle, doing the single bus access the hardware does on it.
+    // Each tick replays the instruction from its start, serving the accesses of
+    // earlier ticks from the trace, so registers only change on its last cycle.
+    pub fn tick<B: Bus>(&mut self, mem: &mut B) -> Result<BusCycle, CpuError> {
+        let (bus, _) = self.tick_step(mem)?;
+        Ok(bus)
+    }
+
+    // Also hands back the StepInfo once the instruction in flight has finished
+    fn tick_step<B: Bus>(&mut self, mem: &mut B) -> Result<(BusCycle, Option<StepInfo>), CpuError> {
+        if let Some(error) = self.jam {
+            return Err(error);
+        }
+        let mut state = match self.tick_state.take() {
+            Some(state) => state,
+            None => {
+                let state = Box::new(TickState {
+                    start: self.clone(),
+                    accesses: Vec::new(),
+                    cycle: 0,
+                });
+                // an NMI raised from here on is taken after this instruction
+                self.nmi_pending = false;
+                state
             }
-            instructions::LDA::ZP => {
-                self.handle_LDA_ZP(mem);
+        };
+
+        let mut cpu = state.start.clone();
+        cpu.trace = Some(BusTrace {
+            accesses: std::mem::take(&mut state.accesses),
+            target: state.cycle,
+            position: 0,
+        });
+        let result = cpu.execute(mem);
+        let Some(trace) = cpu.trace.take() else {
+            unreachable!("replay lost its trace");
+        };
+        let bus = match trace.accesses.get(state.cycle) {
+            Some(access) => *access,
+            None => BusCycle::Idle,
+        };
+        state.accesses = trace.accesses;
+        state.cycle += 1;
+        self.Cycles += 1;
+
+        // a JAM finishes with the opcode fetch
+        let cycles = match result {
+            Ok(step) => step.Cycles as usize,
+            Err(_) => 0,
+        };
+        if state.cycle < cycles.max(trace.position) {
+            self.tick_state = Some(state);
+            return Ok((bus, None));
+        }
+        // the lines may have changed while the instruction was in flight
+        cpu.irq_line = self.irq_line;
+        cpu.nmi_line = self.nmi_line;
+        cpu.nmi_pending |= self.nmi_pending;
+        cpu.Cycles = self.Cycles;
+        *self = cpu;
+        let step = result?;
+        Ok((bus, Some(step)))
+    }
+
+    // Executes whole instructions until at least `cycles` have passed, returning
+    // the cycles actually spent since the last instruction may overshoot the budget
+    pub fn run_cycles<B: Bus>(&mut self, mem: &mut B, cycles: u64) -> Result<u64, CpuError> {
+        let mut spent: u64 = 0;
+        while spent < cycles {
+            spent += self.execute(mem)?.Cycles as u64;
+        }
+        Ok(spent)
+    }
+
+    // Executes instructions until `done` holds, returning the cycles spent
+    pub fn run_until<B: Bus>(
+        &mut self,
+        mem: &mut B,
+        mut done: impl FnMut(&CPU, &B) -> bool,
+    ) -> Result<u64, CpuError> {
+        let mut spent: u64 = 0;
+        while !done(self, &*mem) {
+            spent += self.execute(mem)?.Cycles as u64;
+        }
+        Ok(spent)
+    }
+
+    // Returns the opcode (if one was fetched) and its base cycles, extra_cycles holds the rest
+    fn step<B: Bus>(&mut self, mem: &mut B) -> (Option<Byte>, Byte) {
+        // a stopped or waiting CPU still sees the clock go by
+        if self.halted {
+            return (None, 1);
+        }
+        if self.waiting {
+            // an IRQ wakes the CPU even while masked, execution then simply resumes
+            if !self.nmi_pending && !self.irq_line {
+                return (None, 1);
             }
-            instructions::LDA::ZPX => self.handle_LDA_ZPX(mem),
-            instructions::LDA::ABS => self.handle_LDA_ABS(mem),
-            instructions::LDA::ABSX => self.handle_LDA_ABSX(mem),
-            instructions::LDA::ABSY => self.handle_LDA_ABSY(mem),
-            instructions::LDA::INDX => self.handle_LDA_INDX(mem),
-            instructions::LDA::INDY => self.handle_LDA_INDY(mem),
-            instructions::LDX::IMM => self.handle_LDX_IMM(mem),
-            instructions::LDX::ZP => self.handle_LDX_ZP(mem),
-            instructions::LDX::ZPY => self.handle_LDX_ZPY(mem),
-            instructions::LDX::ABS => self.handle_LDX_ABS(mem),
-            instructions::LDX::ABSY => self.handle_LDX_ABSY(mem),
-            instructions::LDY::IMM => self.handle_LDY_IMM(mem),
-            instructions::LDY::ZP => self.handle_LDY_ZP(mem),
-            instructions::LDY::ZPX => self.handle_LDY_ZPX(mem),
-            instructions::LDY::ABS => self.handle_LDY_ABS(mem),
-            instructions::LDY::ABSX => self.handle_LDY_ABSX(mem),
-            instructions::JMP::ABS => self.handle_JMP_ABS(mem),
-            instructions::JMP::IND => self.handle_JMP_IND(mem),
-            instructions::INX::IMP => self.handle_INX_IMP(),
-            instructions::INY::IMP => self.handle_INY_IMP(),
-
-            _ => panic!("Unknown opcode: {:X}", opcode),
+            self.waiting = false;
+        }
+        if self.nmi_pending {
+            self.nmi_pending = false;
+            self.hardware_interrupt(mem, NMI_VECTOR);
+            return (None, INTERRUPT_CYCLES);
+        }
+        if self.irq_line && !self.Status.InterruptDisable {
+            self.hardware_interrupt(mem, IRQ_VECTOR);
+            return (None, INTERRUPT_CYCLES);
+        }
+
+        let opcode = self.fetch_byte(mem);
+        let info = opcodes::decode(&self.quirks, opcode);
+        // every instruction reads the byte after its opcode; single byte ones just
+        // throw it away. The 1 cycle 65C02 NOPs and JAM never get that far.
+        if info.Bytes == 1 && info.Cycles > 1 {
+            self.read_byte(mem, self.PC);
+        }
+        self.opcode = opcode;
+        (info.handler)(self, mem);
+        (Some(opcode), info.Cycles)
+    }
+
+    // The opcode fetch is turned into a BRK: PC is read twice without being incremented
+    fn hardware_interrupt<B: Bus + ?Sized>(&mut self, mem: &mut B, vector: Word) {
+        self.read_byte(mem, self.PC);
+        self.read_byte(mem, self.PC);
+        self.interrupt(mem, vector, false);
+    }
+
+    fn interrupt<B: Bus + ?Sized>(&mut self, mem: &mut B, vector: Word, brk: bool) {
+        self.push_word(mem, self.PC);
+        let status = self.Status.to_byte(brk);
+        self.push_byte(mem, status);
+        self.Status.InterruptDisable = true;
+        if self.quirks.Cmos {
+            self.Status.DecimalMode = false;
         }
+        let lo = self.read_byte(mem, vector);
+        let hi = self.read_byte(mem, vector + 0x01);
+        self.PC = ((hi as u16) << 8) | lo as u16;
     }
 
     fn set_flags_LDA(&mut self) {
@@ -130,189 +569,1896 @@ impl CPU {
         self.Status.Negative = (self.Y & 0b1000_0000) > 0;
     }
 
-    fn ZP_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
+    fn set_flags_NZ(&mut self, value: Byte) {
+        self.Status.Zero = value == 0;
+        self.Status.Negative = (value & 0b1000_0000) > 0;
+    }
+
+    fn add_with_carry(&mut self, value: Byte) {
+        if self.Status.DecimalMode && self.quirks.DecimalMode {
+            self.add_with_carry_decimal(value)
+        } else {
+            self.add_with_carry_binary(value)
+        }
+    }
+
+    fn add_with_carry_binary(&mut self, value: Byte) {
+        let sum = self.A as Word + value as Word + self.Status.Carry as Word;
+        let result = sum as Byte;
+        // signed overflow: both operands share a sign that differs from the result
+        self.Status.Overflow = (!(self.A ^ value) & (self.A ^ result) & 0b1000_0000) > 0;
+        self.Status.Carry = sum > 0xFF;
+        self.A = result;
+        self.set_flags_LDA()
+    }
+
+    // http://www.6502.org/tutorials/decimal_mode.html
+    fn add_with_carry_decimal(&mut self, value: Byte) {
+        let carry = self.Status.Carry as Word;
+        let binary = (self.A as Word + value as Word + carry) as Byte;
+
+        let mut lo = (self.A & 0x0F) as Word + (value & 0x0F) as Word + carry;
+        if lo >= 0x0A {
+            lo = ((lo + 0x06) & 0x0F) + 0x10;
+        }
+        let mut sum = (self.A & 0xF0) as Word + (value & 0xF0) as Word + lo;
+
+        // N and V come from the intermediate result, before the high nibble is adjusted
+        let signed = (self.A & 0xF0) as i8 as i16 + (value & 0xF0) as i8 as i16 + lo as i16;
+        self.Status.Overflow = !(-128..=127).contains(&signed);
+        self.Status.Negative = (sum & 0b1000_0000) > 0;
+
+        if sum >= 0xA0 {
+            sum += 0x60;
+        }
+        self.Status.Carry = sum >= 0x100;
+        self.A = sum as Byte;
+
+        if self.quirks.Cmos {
+            self.set_flags_LDA();
+            self.extra_cycles += 1;
+        } else {
+            // Z reflects the binary sum
+            self.Status.Zero = binary == 0;
+        }
+    }
+
+    fn subtract_with_carry(&mut self, value: Byte) {
+        if self.Status.DecimalMode && self.quirks.DecimalMode {
+            self.subtract_with_carry_decimal(value)
+        } else {
+            // A - M - (1 - C) == A + !M + C
+            self.add_with_carry_binary(!value)
+        }
+    }
+
+    fn subtract_with_carry_decimal(&mut self, value: Byte) {
+        let a = self.A;
+        let borrow = !self.Status.Carry as i16;
+
+        // C and V (and N/Z on NMOS) match the binary subtraction
+        self.add_with_carry_binary(!value);
+
+        let mut lo = (a & 0x0F) as i16 - (value & 0x0F) as i16 - borrow;
+        let result = if self.quirks.Cmos {
+            let mut result = a as i16 - value as i16 - borrow;
+            if result < 0 {
+                result -= 0x60;
+            }
+            if lo < 0 {
+                result -= 0x06;
+            }
+            result
+        } else {
+            if lo < 0 {
+                lo = ((lo - 0x06) & 0x0F) - 0x10;
+            }
+            let mut result = (a & 0xF0) as i16 - (value & 0xF0) as i16 + lo;
+            if result < 0 {
+                result -= 0x60;
+            }
+            result
+        };
+        self.A = result as Byte;
+
+        if self.quirks.Cmos {
+            self.set_flags_LDA();
+            self.extra_cycles += 1;
+        }
+    }
+
+    fn bit_test(&mut self, value: Byte) {
+        self.Status.Zero = (self.A & value) == 0;
+        // bits 6 and 7 of the operand are copied straight into V and N
+        self.Status.Overflow = (value & 0b0100_0000) > 0;
+        self.Status.Negative = (value & 0b1000_0000) > 0;
+    }
+
+    fn compare(&mut self, register: Byte, value: Byte) {
+        self.Status.Carry = register >= value;
+        self.set_flags_NZ(register.wrapping_sub(value));
+    }
+
+    fn branch<B: Bus + ?Sized>(&mut self, mem: &mut B, condition: bool) {
+        // the offset is relative to the address of the next instruction
+        let offset = self.fetch_byte(mem) as i8;
+        if !condition {
+            return;
+        }
+        let target = self.PC.wrapping_add(offset as Word);
+        // the next opcode is read while the offset is added, then the address
+        // with the unfixed high byte if the target is on another page
+        self.read_byte(mem, self.PC);
+        self.extra_cycles += 1;
+        if (target & 0xFF00) != (self.PC & 0xFF00) {
+            self.read_byte(mem, (self.PC & 0xFF00) | (target & 0x00FF));
+            self.extra_cycles += 1;
+        }
+        self.PC = target;
+    }
+
+    fn and_rotate_right(&mut self, value: Byte) {
+        let masked = self.A & value;
+        let mut result = (masked >> 1) | ((self.Status.Carry as Byte) << 7);
+        self.set_flags_NZ(result);
+        if self.Status.DecimalMode && self.quirks.DecimalMode {
+            // http://www.oxyron.de/html/opcodes02.html
+            self.Status.Overflow = ((masked ^ result) & 0b0100_0000) > 0;
+            if (masked & 0x0F) + (masked & 0x01) > 0x05 {
+                result = (result & 0xF0) | (result.wrapping_add(0x06) & 0x0F);
+            }
+            self.Status.Carry = (masked >> 4) + ((masked >> 4) & 0x01) > 0x05;
+            if self.Status.Carry {
+                result = result.wrapping_add(0x60);
+            }
+        } else {
+            self.Status.Carry = (result & 0b0100_0000) > 0;
+            self.Status.Overflow = ((result >> 6) ^ (result >> 5)) & 0x01 > 0;
+        }
+        self.A = result;
+    }
+
+    // SHA/SHX/SHY/TAS store `value & (H + 1)`, H being the high byte of the base address.
+    // When indexing crosses a page that value also replaces the high byte of the target.
+    fn store_and_high_byte<B: Bus + ?Sized>(
+        &mut self,
+        mem: &mut B,
+        base_address: Word,
+        index: Byte,
+        value: Byte,
+    ) {
+        let (mut address, page_crossed) = CPU::index_address(base_address, index);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = value & ((base_address >> 8) as Byte).wrapping_add(1);
+        if page_crossed {
+            address = ((value as Word) << 8) | (address & 0x00FF);
+        }
+        self.write_byte(mem, address, value);
+    }
+
+    fn read_modify_write<B: Bus + ?Sized>(
+        &mut self,
+        mem: &mut B,
+        address: Word,
+        op: impl FnOnce(&mut CPU, Byte) -> Byte,
+    ) -> Byte {
+        let value = self.read_byte(mem, address);
+        if self.quirks.Cmos {
+            // CMOS parts read the address a second time instead
+            self.read_byte(mem, address);
+        } else {
+            // the unmodified value is written back before the result
+            self.write_byte(mem, address, value);
+        }
+        let result = op(self, value);
+        self.write_byte(mem, address, result);
+        result
+    }
+
+    fn test_and_reset_bits(&mut self, value: Byte) -> Byte {
+        self.Status.Zero = (self.A & value) == 0;
+        value & !self.A
+    }
+
+    fn test_and_set_bits(&mut self, value: Byte) -> Byte {
+        self.Status.Zero = (self.A & value) == 0;
+        value | self.A
+    }
+
+    fn increment(&mut self, value: Byte) -> Byte {
+        let result = value.wrapping_add(1);
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn decrement(&mut self, value: Byte) -> Byte {
+        let result = value.wrapping_sub(1);
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn shift_left(&mut self, value: Byte) -> Byte {
+        self.Status.Carry = (value & 0b1000_0000) > 0;
+        let result = value << 1;
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn shift_right(&mut self, value: Byte) -> Byte {
+        self.Status.Carry = (value & 0b0000_0001) > 0;
+        let result = value >> 1;
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn rotate_left(&mut self, value: Byte) -> Byte {
+        let result = (value << 1) | self.Status.Carry as Byte;
+        self.Status.Carry = (value & 0b1000_0000) > 0;
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn rotate_right(&mut self, value: Byte) -> Byte {
+        let result = (value >> 1) | ((self.Status.Carry as Byte) << 7);
+        self.Status.Carry = (value & 0b0000_0001) > 0;
+        self.set_flags_NZ(result);
+        result
+    }
+
+    fn ZP_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
         let address: Word = self.fetch_byte(mem) as Word;
         address
     }
 
-    fn ABSX_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
+    fn ZPX_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
+        // zero page index wraps around within page zero
+        let address: Byte = self.fetch_byte(mem);
+        // the unindexed address is read while X is being added
+        self.read_byte(mem, address as Word);
+        address.wrapping_add(self.X) as Word
+    }
+
+    fn ZPY_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
+        let address: Byte = self.fetch_byte(mem);
+        self.read_byte(mem, address as Word);
+        address.wrapping_add(self.Y) as Word
+    }
+
+    // (zp) - 65C02 zero page indirect
+    fn IND_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
+        let zp_address: Byte = self.fetch_byte(mem);
+        let lo = self.read_byte(mem, zp_address as Word);
+        let hi = self.read_byte(mem, zp_address.wrapping_add(1) as Word);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    // Indexed modes also report whether the index carried into the high byte
+    fn ABSX_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> (Word, bool) {
         let base_address: Word = self.fetch_word(mem);
-        
-        base_address + self.X as Word
+        CPU::index_address(base_address, self.X)
     }
 
-    fn ABSY_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
+    fn ABSY_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> (Word, bool) {
         let base_address: Word = self.fetch_word(mem);
-        
-        base_address + self.Y as Word
+        CPU::index_address(base_address, self.Y)
     }
 
-    fn ABS_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
+    fn ABS_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
         let address: Word = self.fetch_word(mem);
         address
     }
 
-    fn INDX_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
-        let mut address: Word = self.fetch_byte(mem) as Word;
-        address += self.X as Word;
-        let lo = self.read_byte(mem, address);
-        let hi = self.read_byte(mem, address + 0x01);
-        
+    fn INDX_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> Word {
+        // both the indexed pointer and its high byte stay in page zero
+        let zp_address: Byte = self.fetch_byte(mem);
+        self.read_byte(mem, zp_address as Word);
+        let zp_address = zp_address.wrapping_add(self.X);
+        let lo = self.read_byte(mem, zp_address as Word);
+        let hi = self.read_byte(mem, zp_address.wrapping_add(1) as Word);
+
         ((hi as u16) << 8) | lo as u16
     }
 
-    fn INDY_ADDRESSING(&mut self, mem: &mut Memory) -> Word {
-        let zp_address: Word = self.fetch_byte(mem) as Word;
-        let lo = self.read_byte(mem, zp_address);
-        let hi = self.read_byte(mem, zp_address + 0x01);
-        let base_address = (((hi as u16) << 8) | lo as u16);
-        
-        base_address + self.Y as u16
+    fn INDY_ADDRESSING<B: Bus + ?Sized>(&mut self, mem: &mut B) -> (Word, bool) {
+        // a pointer at $FF takes its high byte from $00
+        let zp_address: Byte = self.fetch_byte(mem);
+        let lo = self.read_byte(mem, zp_address as Word);
+        let hi = self.read_byte(mem, zp_address.wrapping_add(1) as Word);
+        let base_address = ((hi as u16) << 8) | lo as u16;
+        CPU::index_address(base_address, self.Y)
+    }
+
+    // Indexed reads look at the address before the carry reaches the high byte,
+    // so crossing a page costs a cycle spent reading the wrong address
+    fn read_page_crossing<B: Bus + ?Sized>(
+        &mut self,
+        mem: &mut B,
+        address: Word,
+        page_crossed: bool,
+    ) {
+        if page_crossed {
+            self.extra_cycles += 1;
+            self.read_unfixed_address(mem, address, page_crossed);
+        }
+    }
+
+    // Stores and read-modify-write always take that cycle, crossing a page or not
+    fn read_unfixed_address<B: Bus + ?Sized>(
+        &mut self,
+        mem: &mut B,
+        address: Word,
+        page_crossed: bool,
+    ) {
+        let unfixed = if page_crossed {
+            address.wrapping_sub(0x0100)
+        } else {
+            address
+        };
+        self.read_byte(mem, unfixed);
+    }
+
+    // Wraps at the end of the address space like the hardware adder
+    fn index_address(base_address: Word, index: Byte) -> (Word, bool) {
+        let address = base_address.wrapping_add(index as Word);
+        (address, (address & 0xFF00) != (base_address & 0xFF00))
     }
 
-    fn handle_LDA_IMM(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDA_IMM(&mut self, mem: &mut dyn Bus) {
         let value = self.fetch_byte(mem);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_ZP(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDA_ZP(&mut self, mem: &mut dyn Bus) {
         let address = self.ZP_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_ZPX(&mut self, mem: &mut Memory) {
-        let address = self.ZP_ADDRESSING(mem);
+    pub(crate) fn handle_LDA_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
-        self.A = value + self.X;
+        self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_ABS(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDA_ABS(&mut self, mem: &mut dyn Bus) {
         let address = self.ABS_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
-        self.A = value + self.Y;
+        self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_ABSX(&mut self, mem: &mut Memory) {
-        let address = self.ABSX_ADDRESSING(mem);
+    pub(crate) fn handle_LDA_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
         let value = self.read_byte(mem, address);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_ABSY(&mut self, mem: &mut Memory) {
-        let address = self.ABSY_ADDRESSING(mem);
+    pub(crate) fn handle_LDA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
         let value = self.read_byte(mem, address);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_INDX(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDA_INDX(&mut self, mem: &mut dyn Bus) {
         let address = self.INDX_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDA_INDY(&mut self, mem: &mut Memory) {
-        let address = self.INDY_ADDRESSING(mem);
+    pub(crate) fn handle_LDA_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
         let value = self.read_byte(mem, address);
         self.A = value;
         self.set_flags_LDA()
     }
 
-    fn handle_LDX_IMM(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDX_IMM(&mut self, mem: &mut dyn Bus) {
         let value = self.fetch_byte(mem);
         self.X = value;
         self.set_flags_LDX()
     }
 
-    fn handle_LDX_ZP(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDX_ZP(&mut self, mem: &mut dyn Bus) {
         let address = self.ZP_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.X = value;
         self.set_flags_LDX()
     }
 
-    fn handle_LDX_ZPY(&mut self, mem: &mut Memory) {
-        let address = self.ZP_ADDRESSING(mem);
+    pub(crate) fn handle_LDX_ZPY(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPY_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
-        self.X = value + self.Y;
+        self.X = value;
         self.set_flags_LDX()
     }
 
-    fn handle_LDX_ABS(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDX_ABS(&mut self, mem: &mut dyn Bus) {
         let address = self.ABS_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.X = value;
         self.set_flags_LDX()
     }
 
-    fn handle_LDX_ABSY(&mut self, mem: &mut Memory) {
-        let address = self.ABSY_ADDRESSING(mem);
+    pub(crate) fn handle_LDX_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
         let value = self.read_byte(mem, address);
         self.X = value;
         self.set_flags_LDX()
     }
 
-    fn handle_LDY_IMM(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDY_IMM(&mut self, mem: &mut dyn Bus) {
         let value = self.fetch_byte(mem);
         self.Y = value;
         self.set_flags_LDY()
     }
 
-    fn handle_LDY_ZP(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDY_ZP(&mut self, mem: &mut dyn Bus) {
         let address = self.ZP_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.Y = value;
         self.set_flags_LDY()
     }
 
-    fn handle_LDY_ZPX(&mut self, mem: &mut Memory) {
-        let address = self.ZP_ADDRESSING(mem);
+    pub(crate) fn handle_LDY_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
-        self.Y = value + self.X;
+        self.Y = value;
         self.set_flags_LDY()
     }
 
-    fn handle_LDY_ABS(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_LDY_ABS(&mut self, mem: &mut dyn Bus) {
         let address = self.ABS_ADDRESSING(mem);
         let value = self.read_byte(mem, address);
         self.Y = value;
         self.set_flags_LDY()
     }
 
-    fn handle_LDY_ABSX(&mut self, mem: &mut Memory) {
-        let address = self.ABSX_ADDRESSING(mem);
+    pub(crate) fn handle_LDY_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
         let value = self.read_byte(mem, address);
         self.Y = value;
         self.set_flags_LDY()
     }
 
-    fn handle_JMP_ABS(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_JMP_ABS(&mut self, mem: &mut dyn Bus) {
         let address = self.fetch_word(mem);
         self.PC = address;
     }
 
-    fn handle_JMP_IND(&mut self, mem: &mut Memory) {
+    pub(crate) fn handle_JMP_IND(&mut self, mem: &mut dyn Bus) {
         let address = self.fetch_word(mem);
         let lo = self.read_byte(mem, address);
-        let hi = self.read_byte(mem, address + 0x01);
+        let hi_address = if self.quirks.JmpIndirectPageBug {
+            // NMOS never carries into the high byte, so JMP ($xxFF) reads $xx00
+            (address & 0xFF00) | (address.wrapping_add(1) & 0x00FF)
+        } else {
+            address.wrapping_add(1)
+        };
+        let hi = self.read_byte(mem, hi_address);
         let address = ((hi as u16) << 8) | lo as u16;
         self.PC = address;
     }
 
-    fn handle_INX_IMP(&mut self) {
+    pub(crate) fn handle_INX_IMP(&mut self, _mem: &mut dyn Bus) {
         self.X = self.X.wrapping_add(1);
         self.set_flags_LDX()
     }
 
-    fn handle_INY_IMP(&mut self) {
+    pub(crate) fn handle_INY_IMP(&mut self, _mem: &mut dyn Bus) {
         self.Y = self.Y.wrapping_add(1);
         self.set_flags_LDY()
     }
+
+    pub(crate) fn handle_STA_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STA_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_STX_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.write_byte(mem, address, self.X);
+    }
+
+    pub(crate) fn handle_STX_ZPY(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPY_ADDRESSING(mem);
+        self.write_byte(mem, address, self.X);
+    }
+
+    pub(crate) fn handle_STX_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.write_byte(mem, address, self.X);
+    }
+
+    pub(crate) fn handle_STY_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.write_byte(mem, address, self.Y);
+    }
+
+    pub(crate) fn handle_STY_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.write_byte(mem, address, self.Y);
+    }
+
+    pub(crate) fn handle_STY_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.write_byte(mem, address, self.Y);
+    }
+
+    pub(crate) fn handle_ADC_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ADC_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SBC_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_AND_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ORA_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_BIT_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.bit_test(value);
+    }
+
+    pub(crate) fn handle_BIT_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.bit_test(value);
+    }
+
+    pub(crate) fn handle_ASL_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.shift_left(self.A);
+    }
+
+    pub(crate) fn handle_ASL_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_left);
+    }
+
+    pub(crate) fn handle_ASL_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_left);
+    }
+
+    pub(crate) fn handle_ASL_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_left);
+    }
+
+    pub(crate) fn handle_ASL_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        // the 65C02 only spends the fix-up cycle when the index crosses a page
+        if self.quirks.Cmos {
+            self.read_page_crossing(mem, address, page_crossed);
+        } else {
+            self.read_unfixed_address(mem, address, page_crossed);
+        }
+        self.read_modify_write(mem, address, CPU::shift_left);
+    }
+
+    pub(crate) fn handle_LSR_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.shift_right(self.A);
+    }
+
+    pub(crate) fn handle_LSR_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_right);
+    }
+
+    pub(crate) fn handle_LSR_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_right);
+    }
+
+    pub(crate) fn handle_LSR_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::shift_right);
+    }
+
+    pub(crate) fn handle_LSR_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        if self.quirks.Cmos {
+            self.read_page_crossing(mem, address, page_crossed);
+        } else {
+            self.read_unfixed_address(mem, address, page_crossed);
+        }
+        self.read_modify_write(mem, address, CPU::shift_right);
+    }
+
+    pub(crate) fn handle_ROL_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.rotate_left(self.A);
+    }
+
+    pub(crate) fn handle_ROL_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_left);
+    }
+
+    pub(crate) fn handle_ROL_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_left);
+    }
+
+    pub(crate) fn handle_ROL_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_left);
+    }
+
+    pub(crate) fn handle_ROL_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        if self.quirks.Cmos {
+            self.read_page_crossing(mem, address, page_crossed);
+        } else {
+            self.read_unfixed_address(mem, address, page_crossed);
+        }
+        self.read_modify_write(mem, address, CPU::rotate_left);
+    }
+
+    pub(crate) fn handle_ROR_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.rotate_right(self.A);
+    }
+
+    pub(crate) fn handle_ROR_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_right);
+    }
+
+    pub(crate) fn handle_ROR_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_right);
+    }
+
+    pub(crate) fn handle_ROR_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::rotate_right);
+    }
+
+    pub(crate) fn handle_ROR_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        if self.quirks.Cmos {
+            self.read_page_crossing(mem, address, page_crossed);
+        } else {
+            self.read_unfixed_address(mem, address, page_crossed);
+        }
+        self.read_modify_write(mem, address, CPU::rotate_right);
+    }
+
+    pub(crate) fn handle_CMP_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CMP_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_CPX_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.compare(self.X, value);
+    }
+
+    pub(crate) fn handle_CPX_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.X, value);
+    }
+
+    pub(crate) fn handle_CPX_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.X, value);
+    }
+
+    pub(crate) fn handle_CPY_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.compare(self.Y, value);
+    }
+
+    pub(crate) fn handle_CPY_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.Y, value);
+    }
+
+    pub(crate) fn handle_CPY_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.Y, value);
+    }
+
+    pub(crate) fn handle_BCC_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, !self.Status.Carry);
+    }
+
+    pub(crate) fn handle_BCS_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, self.Status.Carry);
+    }
+
+    pub(crate) fn handle_BEQ_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, self.Status.Zero);
+    }
+
+    pub(crate) fn handle_BNE_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, !self.Status.Zero);
+    }
+
+    pub(crate) fn handle_BMI_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, self.Status.Negative);
+    }
+
+    pub(crate) fn handle_BPL_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, !self.Status.Negative);
+    }
+
+    pub(crate) fn handle_BVC_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, !self.Status.Overflow);
+    }
+
+    pub(crate) fn handle_BVS_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, self.Status.Overflow);
+    }
+
+    pub(crate) fn handle_PHA_IMP(&mut self, mem: &mut dyn Bus) {
+        self.push_byte(mem, self.A);
+    }
+
+    pub(crate) fn handle_PHP_IMP(&mut self, mem: &mut dyn Bus) {
+        // B and bit 5 are always set in the pushed copy
+        let status = self.Status.to_byte(true);
+        self.push_byte(mem, status);
+    }
+
+    pub(crate) fn handle_PLA_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        self.A = self.pull_byte(mem);
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_PLP_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        let status = self.pull_byte(mem);
+        self.Status.set_from_byte(status);
+    }
+
+    pub(crate) fn handle_JSR_ABS(&mut self, mem: &mut dyn Bus) {
+        let lo = self.fetch_byte(mem);
+        self.peek_stack(mem);
+        // PC now points at the last byte of the JSR, which is what gets pushed
+        self.push_word(mem, self.PC);
+        let hi = self.fetch_byte(mem);
+        self.PC = ((hi as u16) << 8) | lo as u16;
+    }
+
+    pub(crate) fn handle_RTS_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        let address = self.pull_word(mem);
+        // the pulled address is read once more before it is incremented
+        self.read_byte(mem, address);
+        self.PC = address.wrapping_add(1);
+    }
+
+    pub(crate) fn handle_RTI_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        let status = self.pull_byte(mem);
+        self.Status.set_from_byte(status);
+        self.PC = self.pull_word(mem);
+    }
+
+    pub(crate) fn handle_BRK_IMP(&mut self, mem: &mut dyn Bus) {
+        // BRK is two bytes long, the second one is skipped
+        self.fetch_byte(mem);
+        self.interrupt(mem, IRQ_VECTOR, true);
+    }
+
+    pub(crate) fn handle_TAX_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.X = self.A;
+        self.set_flags_LDX()
+    }
+
+    pub(crate) fn handle_TAY_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Y = self.A;
+        self.set_flags_LDY()
+    }
+
+    pub(crate) fn handle_TXA_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.X;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_TYA_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.Y;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_TSX_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.X = self.SP;
+        self.set_flags_LDX()
+    }
+
+    pub(crate) fn handle_TXS_IMP(&mut self, _mem: &mut dyn Bus) {
+        // the only transfer that leaves the flags alone
+        self.SP = self.X;
+    }
+
+    pub(crate) fn handle_DEX_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.X = self.X.wrapping_sub(1);
+        self.set_flags_LDX()
+    }
+
+    pub(crate) fn handle_DEY_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Y = self.Y.wrapping_sub(1);
+        self.set_flags_LDY()
+    }
+
+    pub(crate) fn handle_INC_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::increment);
+    }
+
+    pub(crate) fn handle_INC_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::increment);
+    }
+
+    pub(crate) fn handle_INC_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::increment);
+    }
+
+    pub(crate) fn handle_INC_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.read_modify_write(mem, address, CPU::increment);
+    }
+
+    pub(crate) fn handle_DEC_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::decrement);
+    }
+
+    pub(crate) fn handle_DEC_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::decrement);
+    }
+
+    pub(crate) fn handle_DEC_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::decrement);
+    }
+
+    pub(crate) fn handle_DEC_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.read_modify_write(mem, address, CPU::decrement);
+    }
+
+    pub(crate) fn handle_CLC_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.Carry = false;
+    }
+
+    pub(crate) fn handle_SEC_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.Carry = true;
+    }
+
+    pub(crate) fn handle_CLI_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.InterruptDisable = false;
+    }
+
+    pub(crate) fn handle_SEI_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.InterruptDisable = true;
+    }
+
+    pub(crate) fn handle_CLV_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.Overflow = false;
+    }
+
+    pub(crate) fn handle_CLD_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.DecimalMode = false;
+    }
+
+    pub(crate) fn handle_SED_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.Status.DecimalMode = true;
+    }
+
+    pub(crate) fn handle_LAX_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LAX_ZPY(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPY_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LAX_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LAX_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LAX_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LAX_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.X = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SAX_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A & self.X);
+    }
+
+    pub(crate) fn handle_SAX_ZPY(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPY_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A & self.X);
+    }
+
+    pub(crate) fn handle_SAX_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A & self.X);
+    }
+
+    pub(crate) fn handle_SAX_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A & self.X);
+    }
+
+    pub(crate) fn handle_DCP_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_DCP_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::decrement);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_ISC_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_ISC_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::increment);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_SLO_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SLO_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_left);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RLA_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_left);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_SRE_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::shift_right);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_RRA_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.INDX_ADDRESSING(mem);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_RRA_INDY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.INDY_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        let value = self.read_modify_write(mem, address, CPU::rotate_right);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_ANC_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A &= value;
+        self.set_flags_LDA();
+        self.Status.Carry = self.Status.Negative;
+    }
+
+    pub(crate) fn handle_ALR_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A = self.shift_right(self.A & value);
+    }
+
+    pub(crate) fn handle_ARR_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.and_rotate_right(value);
+    }
+
+    pub(crate) fn handle_SBX_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        let masked = self.A & self.X;
+        // compare-style subtraction: no borrow in and no decimal mode
+        self.Status.Carry = masked >= value;
+        self.X = masked.wrapping_sub(value);
+        self.set_flags_LDX()
+    }
+
+    pub(crate) fn handle_SHA_ABSY(&mut self, mem: &mut dyn Bus) {
+        let base_address = self.fetch_word(mem);
+        self.store_and_high_byte(mem, base_address, self.Y, self.A & self.X);
+    }
+
+    pub(crate) fn handle_SHA_INDY(&mut self, mem: &mut dyn Bus) {
+        let zp_address = self.fetch_byte(mem);
+        let lo = self.read_byte(mem, zp_address as Word);
+        let hi = self.read_byte(mem, zp_address.wrapping_add(1) as Word);
+        let base_address = ((hi as u16) << 8) | lo as u16;
+        self.store_and_high_byte(mem, base_address, self.Y, self.A & self.X);
+    }
+
+    pub(crate) fn handle_SHX_ABSY(&mut self, mem: &mut dyn Bus) {
+        let base_address = self.fetch_word(mem);
+        self.store_and_high_byte(mem, base_address, self.Y, self.X);
+    }
+
+    pub(crate) fn handle_SHY_ABSX(&mut self, mem: &mut dyn Bus) {
+        let base_address = self.fetch_word(mem);
+        self.store_and_high_byte(mem, base_address, self.X, self.Y);
+    }
+
+    pub(crate) fn handle_TAS_ABSY(&mut self, mem: &mut dyn Bus) {
+        let base_address = self.fetch_word(mem);
+        self.SP = self.A & self.X;
+        self.store_and_high_byte(mem, base_address, self.Y, self.SP);
+    }
+
+    pub(crate) fn handle_LAS_ABSY(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSY_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        let value = value & self.SP;
+        self.A = value;
+        self.X = value;
+        self.SP = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ANE_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A = (self.A | UNSTABLE_MAGIC) & self.X & value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_LXA_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        self.A = (self.A | UNSTABLE_MAGIC) & value;
+        self.X = self.A;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_NOP_IMP(&mut self, _mem: &mut dyn Bus) {}
+
+    pub(crate) fn handle_NOP_IMM(&mut self, mem: &mut dyn Bus) {
+        self.fetch_byte(mem);
+    }
+
+    pub(crate) fn handle_NOP_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_byte(mem, address);
+    }
+
+    pub(crate) fn handle_NOP_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.read_byte(mem, address);
+    }
+
+    pub(crate) fn handle_NOP_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_byte(mem, address);
+    }
+
+    pub(crate) fn handle_NOP_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        self.read_byte(mem, address);
+    }
+
+    pub(crate) fn handle_ORA_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A |= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_AND_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A &= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_EOR_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A ^= value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_ADC_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.add_with_carry(value);
+    }
+
+    pub(crate) fn handle_LDA_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.A = value;
+        self.set_flags_LDA()
+    }
+
+    pub(crate) fn handle_CMP_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.compare(self.A, value);
+    }
+
+    pub(crate) fn handle_SBC_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.subtract_with_carry(value);
+    }
+
+    pub(crate) fn handle_STA_IND(&mut self, mem: &mut dyn Bus) {
+        let address = self.IND_ADDRESSING(mem);
+        self.write_byte(mem, address, self.A);
+    }
+
+    pub(crate) fn handle_BIT_IMM(&mut self, mem: &mut dyn Bus) {
+        let value = self.fetch_byte(mem);
+        // there is no memory operand to take N and V from
+        self.Status.Zero = (self.A & value) == 0;
+    }
+
+    pub(crate) fn handle_BIT_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        let value = self.read_byte(mem, address);
+        self.bit_test(value);
+    }
+
+    pub(crate) fn handle_BIT_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_page_crossing(mem, address, page_crossed);
+        let value = self.read_byte(mem, address);
+        self.bit_test(value);
+    }
+
+    pub(crate) fn handle_INC_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.increment(self.A);
+    }
+
+    pub(crate) fn handle_DEC_ACC(&mut self, _mem: &mut dyn Bus) {
+        self.A = self.decrement(self.A);
+    }
+
+    pub(crate) fn handle_JMP_INDX(&mut self, mem: &mut dyn Bus) {
+        let address = self.fetch_word(mem).wrapping_add(self.X as Word);
+        let lo = self.read_byte(mem, address);
+        let hi = self.read_byte(mem, address.wrapping_add(1));
+        self.PC = ((hi as u16) << 8) | lo as u16;
+    }
+
+    pub(crate) fn handle_BRA_REL(&mut self, mem: &mut dyn Bus) {
+        self.branch(mem, true);
+    }
+
+    pub(crate) fn handle_PHX_IMP(&mut self, mem: &mut dyn Bus) {
+        self.push_byte(mem, self.X);
+    }
+
+    pub(crate) fn handle_PHY_IMP(&mut self, mem: &mut dyn Bus) {
+        self.push_byte(mem, self.Y);
+    }
+
+    pub(crate) fn handle_PLX_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        self.X = self.pull_byte(mem);
+        self.set_flags_LDX()
+    }
+
+    pub(crate) fn handle_PLY_IMP(&mut self, mem: &mut dyn Bus) {
+        self.peek_stack(mem);
+        self.Y = self.pull_byte(mem);
+        self.set_flags_LDY()
+    }
+
+    pub(crate) fn handle_STZ_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.write_byte(mem, address, 0);
+    }
+
+    pub(crate) fn handle_STZ_ZPX(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZPX_ADDRESSING(mem);
+        self.write_byte(mem, address, 0);
+    }
+
+    pub(crate) fn handle_STZ_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.write_byte(mem, address, 0);
+    }
+
+    pub(crate) fn handle_STZ_ABSX(&mut self, mem: &mut dyn Bus) {
+        let (address, page_crossed) = self.ABSX_ADDRESSING(mem);
+        self.read_unfixed_address(mem, address, page_crossed);
+        self.write_byte(mem, address, 0);
+    }
+
+    pub(crate) fn handle_TRB_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::test_and_reset_bits);
+    }
+
+    pub(crate) fn handle_TRB_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::test_and_reset_bits);
+    }
+
+    pub(crate) fn handle_TSB_ZP(&mut self, mem: &mut dyn Bus) {
+        let address = self.ZP_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::test_and_set_bits);
+    }
+
+    pub(crate) fn handle_TSB_ABS(&mut self, mem: &mut dyn Bus) {
+        let address = self.ABS_ADDRESSING(mem);
+        self.read_modify_write(mem, address, CPU::test_and_set_bits);
+    }
+
+    pub(crate) fn handle_RMB_SMB_ZP(&mut self, mem: &mut dyn Bus) {
+        let opcode = self.opcode;
+        let address = self.ZP_ADDRESSING(mem);
+        let bit: Byte = 1 << ((opcode >> 4) & 0x07);
+        if opcode & 0x80 == 0 {
+            self.read_modify_write(mem, address, |_, value| value & !bit);
+        } else {
+            self.read_modify_write(mem, address, |_, value| value | bit);
+        }
+    }
+
+    pub(crate) fn handle_BBR_BBS_ZPREL(&mut self, mem: &mut dyn Bus) {
+        let opcode = self.opcode;
+        let address = self.ZP_ADDRESSING(mem);
+        let bit: Byte = 1 << ((opcode >> 4) & 0x07);
+        let value = self.read_byte(mem, address);
+        let set = (value & bit) > 0;
+        self.branch(mem, set == (opcode & 0x80 > 0));
+    }
+
+    pub(crate) fn handle_WAI_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.waiting = true;
+    }
+
+    pub(crate) fn handle_STP_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.halted = true;
+    }
+
+    // KIL/JAM lock up the CPU with the opcode fetch
+    pub(crate) fn handle_JAM_IMP(&mut self, _mem: &mut dyn Bus) {
+        self.jam = Some(CpuError::Jammed {
+            Opcode: self.opcode,
+            PC: self.PC.wrapping_sub(1),
+        });
+    }
 }
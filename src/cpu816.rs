@@ -0,0 +1,1456 @@
+use crate::cpu::{CpuError, StepInfo};
+use crate::memory::LongBus;
+use crate::{instructions, Byte, DoubleWord, Word};
+
+// http://www.6502.org/tutorials/65c816opcodes.html
+const NATIVE_COP_VECTOR: Word = 0xFFE4;
+const NATIVE_BRK_VECTOR: Word = 0xFFE6;
+const NATIVE_NMI_VECTOR: Word = 0xFFEA;
+const NATIVE_IRQ_VECTOR: Word = 0xFFEE;
+const EMULATION_COP_VECTOR: Word = 0xFFF4;
+const EMULATION_NMI_VECTOR: Word = 0xFFFA;
+const EMULATION_IRQ_VECTOR: Word = 0xFFFE;
+
+const ADDRESS_MASK: DoubleWord = 0xFF_FFFF;
+
+const INTERRUPT_CYCLES: Byte = 7;
+
+// Base cycles per opcode with 8-bit registers, DL = 0 and no page crossings.
+// 16-bit operands, DL != 0, indexed page crossings, taken branches and native
+// mode interrupts add to these. MVN and MVP are per byte moved.
+#[rustfmt::skip]
+const CYCLES: [Byte; 256] = [
+//  0  1  2  3  4  5  6  7  8  9  A  B  C  D  E  F
+    7, 6, 7, 4, 5, 3, 5, 6, 3, 2, 2, 4, 6, 4, 6, 5, // 0
+    2, 5, 5, 7, 5, 4, 6, 6, 2, 4, 2, 2, 6, 4, 7, 5, // 1
+    6, 6, 8, 4, 3, 3, 5, 6, 4, 2, 2, 5, 4, 4, 6, 5, // 2
+    2, 5, 5, 7, 4, 4, 6, 6, 2, 4, 2, 2, 4, 4, 7, 5, // 3
+    6, 6, 2, 4, 7, 3, 5, 6, 3, 2, 2, 3, 3, 4, 6, 5, // 4
+    2, 5, 5, 7, 7, 4, 6, 6, 2, 4, 3, 2, 4, 4, 7, 5, // 5
+    6, 6, 6, 4, 3, 3, 5, 6, 4, 2, 2, 6, 5, 4, 6, 5, // 6
+    2, 5, 5, 7, 4, 4, 6, 6, 2, 4, 4, 2, 6, 4, 7, 5, // 7
+    2, 6, 4, 4, 3, 3, 3, 6, 2, 2, 2, 3, 4, 4, 4, 5, // 8
+    2, 6, 5, 7, 4, 4, 4, 6, 2, 5, 2, 2, 4, 5, 5, 5, // 9
+    2, 6, 2, 4, 3, 3, 3, 6, 2, 2, 2, 4, 4, 4, 4, 5, // A
+    2, 5, 5, 7, 4, 4, 4, 6, 2, 4, 2, 2, 4, 4, 4, 5, // B
+    2, 6, 3, 4, 3, 3, 5, 6, 2, 2, 2, 3, 4, 4, 6, 5, // C
+    2, 5, 5, 7, 6, 4, 6, 6, 2, 4, 3, 3, 6, 4, 7, 5, // D
+    2, 6, 3, 4, 3, 3, 5, 6, 2, 2, 2, 3, 4, 4, 6, 5, // E
+    2, 5, 5, 7, 5, 4, 6, 6, 2, 4, 4, 2, 8, 4, 7, 5, // F
+];
+
+// Direct page modes keep the 6502 zero page names
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    ACC,
+    IMM,
+    ZP,
+    ZPX,
+    ZPY,
+    ABS,
+    ABSX,
+    ABSY,
+    ABSL,
+    ABSLX,
+    IND,
+    INDX,
+    INDY,
+    INDL,
+    INDLY,
+    SR,
+    SRIY,
+}
+
+#[allow(non_snake_case)]
+pub struct Flags816 {
+    pub Carry: bool,
+    pub Zero: bool,
+    pub InterruptDisable: bool,
+    pub DecimalMode: bool,
+    pub IndexSelect: bool,  // X: set for 8-bit index registers
+    pub MemorySelect: bool, // M: set for an 8-bit accumulator and memory
+    pub Overflow: bool,
+    pub Negative: bool,
+}
+
+impl Flags816 {
+    // NVMX DIZC
+    pub fn to_byte(&self) -> Byte {
+        (self.Negative as Byte) << 7
+            | (self.Overflow as Byte) << 6
+            | (self.MemorySelect as Byte) << 5
+            | (self.IndexSelect as Byte) << 4
+            | (self.DecimalMode as Byte) << 3
+            | (self.InterruptDisable as Byte) << 2
+            | (self.Zero as Byte) << 1
+            | self.Carry as Byte
+    }
+
+    pub fn set_from_byte(&mut self, value: Byte) {
+        self.Negative = (value & 0b1000_0000) > 0;
+        self.Overflow = (value & 0b0100_0000) > 0;
+        self.MemorySelect = (value & 0b0010_0000) > 0;
+        self.IndexSelect = (value & 0b0001_0000) > 0;
+        self.DecimalMode = (value & 0b0000_1000) > 0;
+        self.InterruptDisable = (value & 0b0000_0100) > 0;
+        self.Zero = (value & 0b0000_0010) > 0;
+        self.Carry = (value & 0b0000_0001) > 0;
+    }
+}
+
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+pub struct CPU816 {
+    pub PC: Word,  // Program Counter
+    pub PBR: Byte, // Program Bank
+    pub DBR: Byte, // Data Bank
+    pub D: Word,   // Direct Page
+    pub SP: Word,  // Stack Pointer
+
+    // Registers
+    pub A: Word, // Accumulator, B in the high byte while it is 8 bits wide
+    pub X: Word,
+    pub Y: Word,
+
+    // Status Register
+    pub Status: Flags816,
+    pub Emulation: bool,
+
+    pub Cycles: u64, // total clock cycles executed
+    extra_cycles: Byte,
+
+    // Interrupt lines
+    irq_line: bool,
+    nmi_line: bool,
+    nmi_pending: bool,
+
+    // WAI parks the CPU until an interrupt arrives, STP until reset
+    waiting: bool,
+    halted: bool,
+}
+
+impl Default for CPU816 {
+    fn default() -> CPU816 {
+        CPU816::new()
+    }
+}
+
+#[allow(non_snake_case)]
+impl CPU816 {
+    pub fn new() -> CPU816 {
+        CPU816 {
+            PC: 0,
+            PBR: 0,
+            DBR: 0,
+            D: 0,
+            SP: 0,
+            A: 0,
+            X: 0,
+            Y: 0,
+            Status: Flags816 {
+                Carry: false,
+                Zero: false,
+                InterruptDisable: false,
+                DecimalMode: false,
+                IndexSelect: false,
+                MemorySelect: false,
+                Overflow: false,
+                Negative: false,
+            },
+            Emulation: true,
+            Cycles: 0,
+            extra_cycles: 0,
+            irq_line: false,
+            nmi_line: false,
+            nmi_pending: false,
+            waiting: false,
+            halted: false,
+        }
+    }
+
+    // Comes up as a 6502: emulation mode, 8-bit registers, banks and direct page zeroed
+    pub fn reset(&mut self) {
+        self.PC = 0xFFFC;
+        self.PBR = 0;
+        self.DBR = 0;
+        self.D = 0;
+        self.SP = 0x01FF;
+        self.X &= 0x00FF;
+        self.Y &= 0x00FF;
+        self.Emulation = true;
+        self.Status = Flags816 {
+            Carry: false,
+            Zero: false,
+            InterruptDisable: true,
+            DecimalMode: false,
+            IndexSelect: true,
+            MemorySelect: true,
+            Overflow: false,
+            Negative: false,
+        };
+        self.nmi_pending = false;
+        self.waiting = false;
+        self.halted = false;
+    }
+
+    pub fn set_irq(&mut self, asserted: bool) {
+        self.irq_line = asserted;
+    }
+
+    pub fn set_nmi(&mut self, asserted: bool) {
+        if asserted && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = asserted;
+    }
+
+    fn accumulator_is_8bit(&self) -> bool {
+        self.Emulation || self.Status.MemorySelect
+    }
+
+    fn index_is_8bit(&self) -> bool {
+        self.Emulation || self.Status.IndexSelect
+    }
+
+    fn long_address(bank: Byte, address: Word) -> DoubleWord {
+        ((bank as DoubleWord) << 16) | address as DoubleWord
+    }
+
+    fn read_byte<B: LongBus>(&mut self, mem: &mut B, address: DoubleWord) -> Byte {
+        mem.read(address & ADDRESS_MASK)
+    }
+
+    fn write_byte<B: LongBus>(&mut self, mem: &mut B, address: DoubleWord, value: Byte) {
+        mem.write(address & ADDRESS_MASK, value);
+    }
+
+    fn read_word<B: LongBus>(&mut self, mem: &mut B, address: DoubleWord) -> Word {
+        let lo = self.read_byte(mem, address);
+        let hi = self.read_byte(mem, address.wrapping_add(1));
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    fn write_word<B: LongBus>(&mut self, mem: &mut B, address: DoubleWord, value: Word) {
+        self.write_byte(mem, address, value as Byte);
+        self.write_byte(mem, address.wrapping_add(1), (value >> 8) as Byte);
+    }
+
+    // Bank 0 pointers wrap at the end of the bank instead of running into bank 1
+    fn read_bank0_word<B: LongBus>(&mut self, mem: &mut B, address: Word) -> Word {
+        let lo = self.read_byte(mem, address as DoubleWord);
+        let hi = self.read_byte(mem, address.wrapping_add(1) as DoubleWord);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    fn fetch_byte<B: LongBus>(&mut self, mem: &mut B) -> Byte {
+        // PC wraps within the program bank
+        let data = self.read_byte(mem, CPU816::long_address(self.PBR, self.PC));
+        self.PC = self.PC.wrapping_add(1);
+        data
+    }
+
+    fn fetch_word<B: LongBus>(&mut self, mem: &mut B) -> Word {
+        let lo = self.fetch_byte(mem);
+        let hi = self.fetch_byte(mem);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    fn fetch_long<B: LongBus>(&mut self, mem: &mut B) -> DoubleWord {
+        let address = self.fetch_word(mem);
+        let bank = self.fetch_byte(mem);
+        CPU816::long_address(bank, address)
+    }
+
+    fn push_byte<B: LongBus>(&mut self, mem: &mut B, value: Byte) {
+        self.push_byte_unwrapped(mem, value);
+        self.confine_stack();
+    }
+
+    fn pull_byte<B: LongBus>(&mut self, mem: &mut B) -> Byte {
+        self.SP = self.SP.wrapping_add(1);
+        self.confine_stack();
+        self.read_byte(mem, self.SP as DoubleWord)
+    }
+
+    fn push_word<B: LongBus>(&mut self, mem: &mut B, value: Word) {
+        self.push_byte(mem, (value >> 8) as Byte);
+        self.push_byte(mem, value as Byte);
+    }
+
+    fn pull_word<B: LongBus>(&mut self, mem: &mut B) -> Word {
+        let lo = self.pull_byte(mem);
+        let hi = self.pull_byte(mem);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    // The emulation mode stack is confined to page $01
+    fn confine_stack(&mut self) {
+        if self.Emulation {
+            self.SP = 0x0100 | (self.SP & 0x00FF);
+        }
+    }
+
+    // PEA, PEI, PER, PHD, PLD, JSL and RTL don't wrap the stack in emulation mode and
+    // may reach $00FF or $0200. They call confine_stack once they are done.
+    fn push_byte_unwrapped<B: LongBus>(&mut self, mem: &mut B, value: Byte) {
+        self.write_byte(mem, self.SP as DoubleWord, value);
+        self.SP = self.SP.wrapping_sub(1);
+    }
+
+    fn pull_byte_unwrapped<B: LongBus>(&mut self, mem: &mut B) -> Byte {
+        self.SP = self.SP.wrapping_add(1);
+        self.read_byte(mem, self.SP as DoubleWord)
+    }
+
+    fn push_word_unwrapped<B: LongBus>(&mut self, mem: &mut B, value: Word) {
+        self.push_byte_unwrapped(mem, (value >> 8) as Byte);
+        self.push_byte_unwrapped(mem, value as Byte);
+    }
+
+    fn pull_word_unwrapped<B: LongBus>(&mut self, mem: &mut B) -> Word {
+        let lo = self.pull_byte_unwrapped(mem);
+        let hi = self.pull_byte_unwrapped(mem);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    fn status_byte(&self, brk: bool) -> Byte {
+        let status = self.Status.to_byte();
+        if self.Emulation {
+            // emulation mode pushes the 6502 layout, with B in place of X
+            (status & 0b1100_1111) | 0b0010_0000 | (brk as Byte) << 4
+        } else {
+            status
+        }
+    }
+
+    // Keeps M, X and the index registers consistent after P or E changed
+    fn update_register_widths(&mut self) {
+        if self.Emulation {
+            self.Status.MemorySelect = true;
+            self.Status.IndexSelect = true;
+        }
+        if self.Status.IndexSelect {
+            self.X &= 0x00FF;
+            self.Y &= 0x00FF;
+        }
+    }
+
+    pub fn execute<B: LongBus>(&mut self, mem: &mut B) -> Result<StepInfo, CpuError> {
+        let pc = self.PC;
+        self.extra_cycles = 0;
+        let (opcode, base_cycles) = self.step(mem);
+        let cycles = base_cycles + self.extra_cycles;
+        self.Cycles += cycles as u64;
+        Ok(StepInfo {
+            PC: pc,
+            Opcode: opcode,
+            Cycles: cycles,
+        })
+    }
+
+    // Runs one instruction or interrupt sequence, returning the opcode and base cycles
+    fn step<B: LongBus>(&mut self, mem: &mut B) -> (Option<Byte>, Byte) {
+        if self.halted {
+            return (None, 1);
+        }
+        if self.waiting {
+            if !self.nmi_pending && !self.irq_line {
+                return (None, 1);
+            }
+            self.waiting = false;
+        }
+        if self.nmi_pending {
+            self.nmi_pending = false;
+            self.interrupt(mem, NATIVE_NMI_VECTOR, EMULATION_NMI_VECTOR, false);
+            return (None, INTERRUPT_CYCLES);
+        }
+        if self.irq_line && !self.Status.InterruptDisable {
+            self.interrupt(mem, NATIVE_IRQ_VECTOR, EMULATION_IRQ_VECTOR, false);
+            return (None, INTERRUPT_CYCLES);
+        }
+
+        let opcode = self.fetch_byte(mem);
+        match opcode {
+            instructions::BRK::IMP => self.handle_BRK_IMP(mem),
+            instructions::ORA::INDX => self.handle_ORA(mem, Mode::INDX),
+            instructions::COP::IMP => self.handle_COP_IMP(mem),
+            instructions::ORA::SR => self.handle_ORA(mem, Mode::SR),
+            instructions::TSB::ZP => self.handle_TSB(mem, Mode::ZP),
+            instructions::ORA::ZP => self.handle_ORA(mem, Mode::ZP),
+            instructions::ASL::ZP => self.handle_ASL(mem, Mode::ZP),
+            instructions::ORA::INDL => self.handle_ORA(mem, Mode::INDL),
+            instructions::PHP::IMP => self.handle_PHP_IMP(mem),
+            instructions::ORA::IMM => self.handle_ORA(mem, Mode::IMM),
+            instructions::ASL::ACC => self.handle_ASL(mem, Mode::ACC),
+            instructions::PHD::IMP => self.handle_PHD_IMP(mem),
+            instructions::TSB::ABS => self.handle_TSB(mem, Mode::ABS),
+            instructions::ORA::ABS => self.handle_ORA(mem, Mode::ABS),
+            instructions::ASL::ABS => self.handle_ASL(mem, Mode::ABS),
+            instructions::ORA::ABSL => self.handle_ORA(mem, Mode::ABSL),
+            instructions::BPL::REL => self.branch(mem, !self.Status.Negative),
+            instructions::ORA::INDY => self.handle_ORA(mem, Mode::INDY),
+            instructions::ORA::IND => self.handle_ORA(mem, Mode::IND),
+            instructions::ORA::SRIY => self.handle_ORA(mem, Mode::SRIY),
+            instructions::TRB::ZP => self.handle_TRB(mem, Mode::ZP),
+            instructions::ORA::ZPX => self.handle_ORA(mem, Mode::ZPX),
+            instructions::ASL::ZPX => self.handle_ASL(mem, Mode::ZPX),
+            instructions::ORA::INDLY => self.handle_ORA(mem, Mode::INDLY),
+            instructions::CLC::IMP => self.Status.Carry = false,
+            instructions::ORA::ABSY => self.handle_ORA(mem, Mode::ABSY),
+            instructions::INC::ACC => self.handle_INC(mem, Mode::ACC),
+            instructions::TCS::IMP => self.handle_TCS_IMP(),
+            instructions::TRB::ABS => self.handle_TRB(mem, Mode::ABS),
+            instructions::ORA::ABSX => self.handle_ORA(mem, Mode::ABSX),
+            instructions::ASL::ABSX => self.handle_ASL(mem, Mode::ABSX),
+            instructions::ORA::ABSLX => self.handle_ORA(mem, Mode::ABSLX),
+            instructions::JSR::ABS => self.handle_JSR_ABS(mem),
+            instructions::AND::INDX => self.handle_AND(mem, Mode::INDX),
+            instructions::JSL::ABSL => self.handle_JSL_ABSL(mem),
+            instructions::AND::SR => self.handle_AND(mem, Mode::SR),
+            instructions::BIT::ZP => self.handle_BIT(mem, Mode::ZP),
+            instructions::AND::ZP => self.handle_AND(mem, Mode::ZP),
+            instructions::ROL::ZP => self.handle_ROL(mem, Mode::ZP),
+            instructions::AND::INDL => self.handle_AND(mem, Mode::INDL),
+            instructions::PLP::IMP => self.handle_PLP_IMP(mem),
+            instructions::AND::IMM => self.handle_AND(mem, Mode::IMM),
+            instructions::ROL::ACC => self.handle_ROL(mem, Mode::ACC),
+            instructions::PLD::IMP => self.handle_PLD_IMP(mem),
+            instructions::BIT::ABS => self.handle_BIT(mem, Mode::ABS),
+            instructions::AND::ABS => self.handle_AND(mem, Mode::ABS),
+            instructions::ROL::ABS => self.handle_ROL(mem, Mode::ABS),
+            instructions::AND::ABSL => self.handle_AND(mem, Mode::ABSL),
+            instructions::BMI::REL => self.branch(mem, self.Status.Negative),
+            instructions::AND::INDY => self.handle_AND(mem, Mode::INDY),
+            instructions::AND::IND => self.handle_AND(mem, Mode::IND),
+            instructions::AND::SRIY => self.handle_AND(mem, Mode::SRIY),
+            instructions::BIT::ZPX => self.handle_BIT(mem, Mode::ZPX),
+            instructions::AND::ZPX => self.handle_AND(mem, Mode::ZPX),
+            instructions::ROL::ZPX => self.handle_ROL(mem, Mode::ZPX),
+            instructions::AND::INDLY => self.handle_AND(mem, Mode::INDLY),
+            instructions::SEC::IMP => self.Status.Carry = true,
+            instructions::AND::ABSY => self.handle_AND(mem, Mode::ABSY),
+            instructions::DEC::ACC => self.handle_DEC(mem, Mode::ACC),
+            instructions::TSC::IMP => self.handle_TSC_IMP(),
+            instructions::BIT::ABSX => self.handle_BIT(mem, Mode::ABSX),
+            instructions::AND::ABSX => self.handle_AND(mem, Mode::ABSX),
+            instructions::ROL::ABSX => self.handle_ROL(mem, Mode::ABSX),
+            instructions::AND::ABSLX => self.handle_AND(mem, Mode::ABSLX),
+            instructions::RTI::IMP => self.handle_RTI_IMP(mem),
+            instructions::EOR::INDX => self.handle_EOR(mem, Mode::INDX),
+            instructions::WDM::IMM => {
+                // reserved, skips its signature byte
+                self.fetch_byte(mem);
+            }
+            instructions::EOR::SR => self.handle_EOR(mem, Mode::SR),
+            instructions::MVP::BLK => self.handle_MVP_BLK(mem),
+            instructions::EOR::ZP => self.handle_EOR(mem, Mode::ZP),
+            instructions::LSR::ZP => self.handle_LSR(mem, Mode::ZP),
+            instructions::EOR::INDL => self.handle_EOR(mem, Mode::INDL),
+            instructions::PHA::IMP => self.handle_PHA_IMP(mem),
+            instructions::EOR::IMM => self.handle_EOR(mem, Mode::IMM),
+            instructions::LSR::ACC => self.handle_LSR(mem, Mode::ACC),
+            instructions::PHK::IMP => self.handle_PHK_IMP(mem),
+            instructions::JMP::ABS => self.handle_JMP_ABS(mem),
+            instructions::EOR::ABS => self.handle_EOR(mem, Mode::ABS),
+            instructions::LSR::ABS => self.handle_LSR(mem, Mode::ABS),
+            instructions::EOR::ABSL => self.handle_EOR(mem, Mode::ABSL),
+            instructions::BVC::REL => self.branch(mem, !self.Status.Overflow),
+            instructions::EOR::INDY => self.handle_EOR(mem, Mode::INDY),
+            instructions::EOR::IND => self.handle_EOR(mem, Mode::IND),
+            instructions::EOR::SRIY => self.handle_EOR(mem, Mode::SRIY),
+            instructions::MVN::BLK => self.handle_MVN_BLK(mem),
+            instructions::EOR::ZPX => self.handle_EOR(mem, Mode::ZPX),
+            instructions::LSR::ZPX => self.handle_LSR(mem, Mode::ZPX),
+            instructions::EOR::INDLY => self.handle_EOR(mem, Mode::INDLY),
+            instructions::CLI::IMP => self.Status.InterruptDisable = false,
+            instructions::EOR::ABSY => self.handle_EOR(mem, Mode::ABSY),
+            instructions::PHY::IMP => self.handle_PHY_IMP(mem),
+            instructions::TCD::IMP => self.handle_TCD_IMP(),
+            instructions::JML::ABSL => self.handle_JML_ABSL(mem),
+            instructions::EOR::ABSX => self.handle_EOR(mem, Mode::ABSX),
+            instructions::LSR::ABSX => self.handle_LSR(mem, Mode::ABSX),
+            instructions::EOR::ABSLX => self.handle_EOR(mem, Mode::ABSLX),
+            instructions::RTS::IMP => self.handle_RTS_IMP(mem),
+            instructions::ADC::INDX => self.handle_ADC(mem, Mode::INDX),
+            instructions::PER::REL => self.handle_PER_REL(mem),
+            instructions::ADC::SR => self.handle_ADC(mem, Mode::SR),
+            instructions::STZ::ZP => self.handle_STZ(mem, Mode::ZP),
+            instructions::ADC::ZP => self.handle_ADC(mem, Mode::ZP),
+            instructions::ROR::ZP => self.handle_ROR(mem, Mode::ZP),
+            instructions::ADC::INDL => self.handle_ADC(mem, Mode::INDL),
+            instructions::PLA::IMP => self.handle_PLA_IMP(mem),
+            instructions::ADC::IMM => self.handle_ADC(mem, Mode::IMM),
+            instructions::ROR::ACC => self.handle_ROR(mem, Mode::ACC),
+            instructions::RTL::IMP => self.handle_RTL_IMP(mem),
+            instructions::JMP::IND => self.handle_JMP_IND(mem),
+            instructions::ADC::ABS => self.handle_ADC(mem, Mode::ABS),
+            instructions::ROR::ABS => self.handle_ROR(mem, Mode::ABS),
+            instructions::ADC::ABSL => self.handle_ADC(mem, Mode::ABSL),
+            instructions::BVS::REL => self.branch(mem, self.Status.Overflow),
+            instructions::ADC::INDY => self.handle_ADC(mem, Mode::INDY),
+            instructions::ADC::IND => self.handle_ADC(mem, Mode::IND),
+            instructions::ADC::SRIY => self.handle_ADC(mem, Mode::SRIY),
+            instructions::STZ::ZPX => self.handle_STZ(mem, Mode::ZPX),
+            instructions::ADC::ZPX => self.handle_ADC(mem, Mode::ZPX),
+            instructions::ROR::ZPX => self.handle_ROR(mem, Mode::ZPX),
+            instructions::ADC::INDLY => self.handle_ADC(mem, Mode::INDLY),
+            instructions::SEI::IMP => self.Status.InterruptDisable = true,
+            instructions::ADC::ABSY => self.handle_ADC(mem, Mode::ABSY),
+            instructions::PLY::IMP => self.handle_PLY_IMP(mem),
+            instructions::TDC::IMP => self.handle_TDC_IMP(),
+            instructions::JMP::INDX => self.handle_JMP_INDX(mem),
+            instructions::ADC::ABSX => self.handle_ADC(mem, Mode::ABSX),
+            instructions::ROR::ABSX => self.handle_ROR(mem, Mode::ABSX),
+            instructions::ADC::ABSLX => self.handle_ADC(mem, Mode::ABSLX),
+            instructions::BRA::REL => self.branch(mem, true),
+            instructions::STA::INDX => self.handle_STA(mem, Mode::INDX),
+            instructions::BRL::REL => self.handle_BRL_REL(mem),
+            instructions::STA::SR => self.handle_STA(mem, Mode::SR),
+            instructions::STY::ZP => self.handle_STY(mem, Mode::ZP),
+            instructions::STA::ZP => self.handle_STA(mem, Mode::ZP),
+            instructions::STX::ZP => self.handle_STX(mem, Mode::ZP),
+            instructions::STA::INDL => self.handle_STA(mem, Mode::INDL),
+            instructions::DEY::IMP => self.handle_DEY_IMP(),
+            instructions::BIT::IMM => self.handle_BIT(mem, Mode::IMM),
+            instructions::TXA::IMP => self.handle_TXA_IMP(),
+            instructions::PHB::IMP => self.handle_PHB_IMP(mem),
+            instructions::STY::ABS => self.handle_STY(mem, Mode::ABS),
+            instructions::STA::ABS => self.handle_STA(mem, Mode::ABS),
+            instructions::STX::ABS => self.handle_STX(mem, Mode::ABS),
+            instructions::STA::ABSL => self.handle_STA(mem, Mode::ABSL),
+            instructions::BCC::REL => self.branch(mem, !self.Status.Carry),
+            instructions::STA::INDY => self.handle_STA(mem, Mode::INDY),
+            instructions::STA::IND => self.handle_STA(mem, Mode::IND),
+            instructions::STA::SRIY => self.handle_STA(mem, Mode::SRIY),
+            instructions::STY::ZPX => self.handle_STY(mem, Mode::ZPX),
+            instructions::STA::ZPX => self.handle_STA(mem, Mode::ZPX),
+            instructions::STX::ZPY => self.handle_STX(mem, Mode::ZPY),
+            instructions::STA::INDLY => self.handle_STA(mem, Mode::INDLY),
+            instructions::TYA::IMP => self.handle_TYA_IMP(),
+            instructions::STA::ABSY => self.handle_STA(mem, Mode::ABSY),
+            instructions::TXS::IMP => self.handle_TXS_IMP(),
+            instructions::TXY::IMP => self.handle_TXY_IMP(),
+            instructions::STZ::ABS => self.handle_STZ(mem, Mode::ABS),
+            instructions::STA::ABSX => self.handle_STA(mem, Mode::ABSX),
+            instructions::STZ::ABSX => self.handle_STZ(mem, Mode::ABSX),
+            instructions::STA::ABSLX => self.handle_STA(mem, Mode::ABSLX),
+            instructions::LDY::IMM => self.handle_LDY(mem, Mode::IMM),
+            instructions::LDA::INDX => self.handle_LDA(mem, Mode::INDX),
+            instructions::LDX::IMM => self.handle_LDX(mem, Mode::IMM),
+            instructions::LDA::SR => self.handle_LDA(mem, Mode::SR),
+            instructions::LDY::ZP => self.handle_LDY(mem, Mode::ZP),
+            instructions::LDA::ZP => self.handle_LDA(mem, Mode::ZP),
+            instructions::LDX::ZP => self.handle_LDX(mem, Mode::ZP),
+            instructions::LDA::INDL => self.handle_LDA(mem, Mode::INDL),
+            instructions::TAY::IMP => self.handle_TAY_IMP(),
+            instructions::LDA::IMM => self.handle_LDA(mem, Mode::IMM),
+            instructions::TAX::IMP => self.handle_TAX_IMP(),
+            instructions::PLB::IMP => self.handle_PLB_IMP(mem),
+            instructions::LDY::ABS => self.handle_LDY(mem, Mode::ABS),
+            instructions::LDA::ABS => self.handle_LDA(mem, Mode::ABS),
+            instructions::LDX::ABS => self.handle_LDX(mem, Mode::ABS),
+            instructions::LDA::ABSL => self.handle_LDA(mem, Mode::ABSL),
+            instructions::BCS::REL => self.branch(mem, self.Status.Carry),
+            instructions::LDA::INDY => self.handle_LDA(mem, Mode::INDY),
+            instructions::LDA::IND => self.handle_LDA(mem, Mode::IND),
+            instructions::LDA::SRIY => self.handle_LDA(mem, Mode::SRIY),
+            instructions::LDY::ZPX => self.handle_LDY(mem, Mode::ZPX),
+            instructions::LDA::ZPX => self.handle_LDA(mem, Mode::ZPX),
+            instructions::LDX::ZPY => self.handle_LDX(mem, Mode::ZPY),
+            instructions::LDA::INDLY => self.handle_LDA(mem, Mode::INDLY),
+            instructions::CLV::IMP => self.Status.Overflow = false,
+            instructions::LDA::ABSY => self.handle_LDA(mem, Mode::ABSY),
+            instructions::TSX::IMP => self.handle_TSX_IMP(),
+            instructions::TYX::IMP => self.handle_TYX_IMP(),
+            instructions::LDY::ABSX => self.handle_LDY(mem, Mode::ABSX),
+            instructions::LDA::ABSX => self.handle_LDA(mem, Mode::ABSX),
+            instructions::LDX::ABSY => self.handle_LDX(mem, Mode::ABSY),
+            instructions::LDA::ABSLX => self.handle_LDA(mem, Mode::ABSLX),
+            instructions::CPY::IMM => self.handle_CPY(mem, Mode::IMM),
+            instructions::CMP::INDX => self.handle_CMP(mem, Mode::INDX),
+            instructions::REP::IMM => self.handle_REP_IMM(mem),
+            instructions::CMP::SR => self.handle_CMP(mem, Mode::SR),
+            instructions::CPY::ZP => self.handle_CPY(mem, Mode::ZP),
+            instructions::CMP::ZP => self.handle_CMP(mem, Mode::ZP),
+            instructions::DEC::ZP => self.handle_DEC(mem, Mode::ZP),
+            instructions::CMP::INDL => self.handle_CMP(mem, Mode::INDL),
+            instructions::INY::IMP => self.handle_INY_IMP(),
+            instructions::CMP::IMM => self.handle_CMP(mem, Mode::IMM),
+            instructions::DEX::IMP => self.handle_DEX_IMP(),
+            instructions::WAI::IMP => self.waiting = true,
+            instructions::CPY::ABS => self.handle_CPY(mem, Mode::ABS),
+            instructions::CMP::ABS => self.handle_CMP(mem, Mode::ABS),
+            instructions::DEC::ABS => self.handle_DEC(mem, Mode::ABS),
+            instructions::CMP::ABSL => self.handle_CMP(mem, Mode::ABSL),
+            instructions::BNE::REL => self.branch(mem, !self.Status.Zero),
+            instructions::CMP::INDY => self.handle_CMP(mem, Mode::INDY),
+            instructions::CMP::IND => self.handle_CMP(mem, Mode::IND),
+            instructions::CMP::SRIY => self.handle_CMP(mem, Mode::SRIY),
+            instructions::PEI::IND => self.handle_PEI_IND(mem),
+            instructions::CMP::ZPX => self.handle_CMP(mem, Mode::ZPX),
+            instructions::DEC::ZPX => self.handle_DEC(mem, Mode::ZPX),
+            instructions::CMP::INDLY => self.handle_CMP(mem, Mode::INDLY),
+            instructions::CLD::IMP => self.Status.DecimalMode = false,
+            instructions::CMP::ABSY => self.handle_CMP(mem, Mode::ABSY),
+            instructions::PHX::IMP => self.handle_PHX_IMP(mem),
+            instructions::STP::IMP => self.halted = true,
+            instructions::JML::INDL => self.handle_JML_INDL(mem),
+            instructions::CMP::ABSX => self.handle_CMP(mem, Mode::ABSX),
+            instructions::DEC::ABSX => self.handle_DEC(mem, Mode::ABSX),
+            instructions::CMP::ABSLX => self.handle_CMP(mem, Mode::ABSLX),
+            instructions::CPX::IMM => self.handle_CPX(mem, Mode::IMM),
+            instructions::SBC::INDX => self.handle_SBC(mem, Mode::INDX),
+            instructions::SEP::IMM => self.handle_SEP_IMM(mem),
+            instructions::SBC::SR => self.handle_SBC(mem, Mode::SR),
+            instructions::CPX::ZP => self.handle_CPX(mem, Mode::ZP),
+            instructions::SBC::ZP => self.handle_SBC(mem, Mode::ZP),
+            instructions::INC::ZP => self.handle_INC(mem, Mode::ZP),
+            instructions::SBC::INDL => self.handle_SBC(mem, Mode::INDL),
+            instructions::INX::IMP => self.handle_INX_IMP(),
+            instructions::SBC::IMM => self.handle_SBC(mem, Mode::IMM),
+            instructions::NOP::IMP => {}
+            instructions::XBA::IMP => self.handle_XBA_IMP(),
+            instructions::CPX::ABS => self.handle_CPX(mem, Mode::ABS),
+            instructions::SBC::ABS => self.handle_SBC(mem, Mode::ABS),
+            instructions::INC::ABS => self.handle_INC(mem, Mode::ABS),
+            instructions::SBC::ABSL => self.handle_SBC(mem, Mode::ABSL),
+            instructions::BEQ::REL => self.branch(mem, self.Status.Zero),
+            instructions::SBC::INDY => self.handle_SBC(mem, Mode::INDY),
+            instructions::SBC::IND => self.handle_SBC(mem, Mode::IND),
+            instructions::SBC::SRIY => self.handle_SBC(mem, Mode::SRIY),
+            instructions::PEA::ABS => self.handle_PEA_ABS(mem),
+            instructions::SBC::ZPX => self.handle_SBC(mem, Mode::ZPX),
+            instructions::INC::ZPX => self.handle_INC(mem, Mode::ZPX),
+            instructions::SBC::INDLY => self.handle_SBC(mem, Mode::INDLY),
+            instructions::SED::IMP => self.Status.DecimalMode = true,
+            instructions::SBC::ABSY => self.handle_SBC(mem, Mode::ABSY),
+            instructions::PLX::IMP => self.handle_PLX_IMP(mem),
+            instructions::XCE::IMP => self.handle_XCE_IMP(),
+            instructions::JSR::INDX => self.handle_JSR_INDX(mem),
+            instructions::SBC::ABSX => self.handle_SBC(mem, Mode::ABSX),
+            instructions::INC::ABSX => self.handle_INC(mem, Mode::ABSX),
+            instructions::SBC::ABSLX => self.handle_SBC(mem, Mode::ABSLX),
+        }
+        (Some(opcode), CYCLES[opcode as usize])
+    }
+
+    fn interrupt<B: LongBus>(&mut self, mem: &mut B, native: Word, emulation: Word, brk: bool) {
+        if !self.Emulation {
+            self.push_byte(mem, self.PBR);
+            self.extra_cycles += 1;
+        }
+        self.push_word(mem, self.PC);
+        let status = self.status_byte(brk);
+        self.push_byte(mem, status);
+        self.Status.InterruptDisable = true;
+        self.Status.DecimalMode = false;
+        self.PBR = 0;
+        let vector = if self.Emulation { emulation } else { native };
+        self.PC = self.read_bank0_word(mem, vector);
+    }
+
+    fn set_flags_NZ(&mut self, value: Word, wide: bool) {
+        if wide {
+            self.Status.Zero = value == 0;
+            self.Status.Negative = (value & 0x8000) > 0;
+        } else {
+            self.Status.Zero = (value & 0x00FF) == 0;
+            self.Status.Negative = (value & 0x0080) > 0;
+        }
+    }
+
+    fn accumulator(&self) -> Word {
+        if self.accumulator_is_8bit() {
+            self.A & 0x00FF
+        } else {
+            self.A
+        }
+    }
+
+    // Writes the active part of the accumulator; B is preserved in 8-bit mode
+    fn set_accumulator(&mut self, value: Word) {
+        if self.accumulator_is_8bit() {
+            self.A = (self.A & 0xFF00) | (value & 0x00FF);
+        } else {
+            self.A = value;
+        }
+        self.set_flags_NZ(value, !self.accumulator_is_8bit());
+    }
+
+    fn set_index(&self, value: Word) -> Word {
+        if self.index_is_8bit() {
+            value & 0x00FF
+        } else {
+            value
+        }
+    }
+
+    // d, d,x and d,y: with DL = 0 in emulation mode the index wraps within the page
+    fn direct_address(&self, offset: Word) -> DoubleWord {
+        if self.Emulation && (self.D & 0x00FF) == 0 {
+            (self.D | (offset & 0x00FF)) as DoubleWord
+        } else {
+            self.D.wrapping_add(offset) as DoubleWord
+        }
+    }
+
+    // Direct page accesses take a cycle more while DL is not zero
+    fn fetch_direct_offset<B: LongBus>(&mut self, mem: &mut B) -> Word {
+        self.extra_cycles += ((self.D & 0x00FF) != 0) as Byte;
+        self.fetch_byte(mem) as Word
+    }
+
+    // Indexed reads take a cycle more when the index crosses a page or is 16 bits wide
+    fn index_cycles(&self, mode: Mode, address: DoubleWord) -> Byte {
+        let index = match mode {
+            Mode::ABSX => self.X,
+            Mode::ABSY | Mode::INDY => self.Y,
+            _ => return 0,
+        };
+        let base = address.wrapping_sub(index as DoubleWord) & ADDRESS_MASK;
+        (!self.index_is_8bit() || ((base ^ address) & 0xFF00) != 0) as Byte
+    }
+
+    fn read_direct_word<B: LongBus>(&mut self, mem: &mut B, offset: Word) -> Word {
+        let lo_address = self.direct_address(offset);
+        let hi_address = self.direct_address(offset.wrapping_add(1));
+        let lo = self.read_byte(mem, lo_address);
+        let hi = self.read_byte(mem, hi_address);
+        ((hi as u16) << 8) | lo as u16
+    }
+
+    // [d] and [d],y never wrap within the page, not even in emulation mode
+    fn read_direct_long<B: LongBus>(&mut self, mem: &mut B, offset: Word) -> DoubleWord {
+        let pointer = self.D.wrapping_add(offset);
+        let lo = self.read_byte(mem, pointer as DoubleWord);
+        let hi = self.read_byte(mem, pointer.wrapping_add(1) as DoubleWord);
+        let bank = self.read_byte(mem, pointer.wrapping_add(2) as DoubleWord);
+        CPU816::long_address(bank, ((hi as u16) << 8) | lo as u16)
+    }
+
+    fn effective_address<B: LongBus>(&mut self, mem: &mut B, mode: Mode) -> DoubleWord {
+        match mode {
+            Mode::ZP => {
+                let offset = self.fetch_direct_offset(mem);
+                self.direct_address(offset)
+            }
+            Mode::ZPX => {
+                let offset = self.fetch_direct_offset(mem);
+                self.direct_address(offset.wrapping_add(self.X))
+            }
+            Mode::ZPY => {
+                let offset = self.fetch_direct_offset(mem);
+                self.direct_address(offset.wrapping_add(self.Y))
+            }
+            Mode::ABS => {
+                let address = self.fetch_word(mem);
+                CPU816::long_address(self.DBR, address)
+            }
+            Mode::ABSX => {
+                let address = self.fetch_word(mem);
+                (CPU816::long_address(self.DBR, address) + self.X as DoubleWord) & ADDRESS_MASK
+            }
+            Mode::ABSY => {
+                let address = self.fetch_word(mem);
+                (CPU816::long_address(self.DBR, address) + self.Y as DoubleWord) & ADDRESS_MASK
+            }
+            Mode::ABSL => self.fetch_long(mem),
+            Mode::ABSLX => (self.fetch_long(mem) + self.X as DoubleWord) & ADDRESS_MASK,
+            Mode::IND => {
+                let offset = self.fetch_direct_offset(mem);
+                let address = self.read_direct_word(mem, offset);
+                CPU816::long_address(self.DBR, address)
+            }
+            Mode::INDX => {
+                let offset = self.fetch_direct_offset(mem);
+                let address = self.read_direct_word(mem, offset.wrapping_add(self.X));
+                CPU816::long_address(self.DBR, address)
+            }
+            Mode::INDY => {
+                let offset = self.fetch_direct_offset(mem);
+                let address = self.read_direct_word(mem, offset);
+                (CPU816::long_address(self.DBR, address) + self.Y as DoubleWord) & ADDRESS_MASK
+            }
+            Mode::INDL => {
+                let offset = self.fetch_direct_offset(mem);
+                self.read_direct_long(mem, offset)
+            }
+            Mode::INDLY => {
+                let offset = self.fetch_direct_offset(mem);
+                (self.read_direct_long(mem, offset) + self.Y as DoubleWord) & ADDRESS_MASK
+            }
+            Mode::SR => {
+                let offset = self.fetch_byte(mem) as Word;
+                self.SP.wrapping_add(offset) as DoubleWord
+            }
+            Mode::SRIY => {
+                let offset = self.fetch_byte(mem) as Word;
+                let address = self.read_bank0_word(mem, self.SP.wrapping_add(offset));
+                (CPU816::long_address(self.DBR, address) + self.Y as DoubleWord) & ADDRESS_MASK
+            }
+            Mode::ACC | Mode::IMM => unreachable!("{:?} has no effective address", mode),
+        }
+    }
+
+    // 16-bit operands cost the cycle of their second byte
+    fn read_operand<B: LongBus>(&mut self, mem: &mut B, mode: Mode, wide: bool) -> Word {
+        self.extra_cycles += wide as Byte;
+        if mode == Mode::IMM {
+            return if wide {
+                self.fetch_word(mem)
+            } else {
+                self.fetch_byte(mem) as Word
+            };
+        }
+        let address = self.effective_address(mem, mode);
+        self.extra_cycles += self.index_cycles(mode, address);
+        if wide {
+            self.read_word(mem, address)
+        } else {
+            self.read_byte(mem, address) as Word
+        }
+    }
+
+    fn write_operand<B: LongBus>(&mut self, mem: &mut B, mode: Mode, value: Word, wide: bool) {
+        self.extra_cycles += wide as Byte;
+        let address = self.effective_address(mem, mode);
+        if wide {
+            self.write_word(mem, address, value);
+        } else {
+            self.write_byte(mem, address, value as Byte);
+        }
+    }
+
+    fn read_modify_write<B: LongBus>(
+        &mut self,
+        mem: &mut B,
+        mode: Mode,
+        op: impl FnOnce(&mut CPU816, Word, bool) -> Word,
+    ) {
+        let wide = !self.accumulator_is_8bit();
+        if mode == Mode::ACC {
+            let value = self.accumulator();
+            let result = op(self, value, wide);
+            self.set_accumulator(result);
+            return;
+        }
+        let address = self.effective_address(mem, mode);
+        if wide {
+            // one more cycle each for reading and writing the high byte
+            self.extra_cycles += 2;
+            let value = self.read_word(mem, address);
+            let result = op(self, value, wide);
+            self.write_word(mem, address, result);
+        } else {
+            let value = self.read_byte(mem, address) as Word;
+            let result = op(self, value, wide);
+            self.write_byte(mem, address, result as Byte);
+        }
+    }
+
+    fn add_with_carry(&mut self, value: Word) {
+        let wide = !self.accumulator_is_8bit();
+        let (mask, sign): (DoubleWord, Word) = if wide { (0xFFFF, 0x8000) } else { (0xFF, 0x80) };
+        let a = self.accumulator();
+        let mut carry = self.Status.Carry as DoubleWord;
+
+        let result = if self.Status.DecimalMode {
+            let digits = if wide { 4 } else { 2 };
+            let mut result: DoubleWord = 0;
+            let mut intermediate: DoubleWord = 0;
+            for digit in 0..digits {
+                let shift = digit * 4;
+                let mut sum = ((a as DoubleWord >> shift) & 0x0F)
+                    + ((value as DoubleWord >> shift) & 0x0F)
+                    + carry;
+                // V is taken before the top digit gets its decimal adjust
+                intermediate = result | (sum << shift);
+                if sum > 0x09 {
+                    sum += 0x06;
+                }
+                carry = (sum > 0x0F) as DoubleWord;
+                result |= (sum & 0x0F) << shift;
+            }
+            let intermediate = intermediate as Word;
+            self.Status.Overflow = (!(a ^ value) & (a ^ intermediate) & sign) > 0;
+            self.Status.Carry = carry > 0;
+            result as Word
+        } else {
+            let sum = a as DoubleWord + value as DoubleWord + carry;
+            let result = (sum & mask) as Word;
+            self.Status.Overflow = (!(a ^ value) & (a ^ result) & sign) > 0;
+            self.Status.Carry = sum > mask;
+            result
+        };
+        self.set_accumulator(result);
+    }
+
+    fn subtract_with_carry(&mut self, value: Word) {
+        if !self.Status.DecimalMode {
+            let mask = if self.accumulator_is_8bit() {
+                0xFF
+            } else {
+                0xFFFF
+            };
+            return self.add_with_carry(!value & mask);
+        }
+
+        let wide = !self.accumulator_is_8bit();
+        let sign: Word = if wide { 0x8000 } else { 0x80 };
+        let a = self.accumulator();
+        let digits = if wide { 4 } else { 2 };
+        let mut borrow = !self.Status.Carry as i32;
+        let mut result: Word = 0;
+        for digit in 0..digits {
+            let shift = digit * 4;
+            let mut difference =
+                ((a >> shift) & 0x0F) as i32 - ((value >> shift) & 0x0F) as i32 - borrow;
+            borrow = (difference < 0) as i32;
+            if difference < 0 {
+                difference += 10;
+            }
+            result |= ((difference as Word) & 0x0F) << shift;
+        }
+        // V follows the binary subtraction
+        let binary = a
+            .wrapping_sub(value)
+            .wrapping_sub(!self.Status.Carry as Word);
+        self.Status.Overflow = ((a ^ value) & (a ^ binary) & sign) > 0;
+        self.Status.Carry = borrow == 0;
+        self.set_accumulator(result);
+    }
+
+    fn compare(&mut self, register: Word, value: Word, wide: bool) {
+        self.Status.Carry = register >= value;
+        self.set_flags_NZ(register.wrapping_sub(value), wide);
+    }
+
+    fn shift_left(&mut self, value: Word, wide: bool) -> Word {
+        let sign = if wide { 0x8000 } else { 0x80 };
+        self.Status.Carry = (value & sign) > 0;
+        let result = value << 1;
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn shift_right(&mut self, value: Word, wide: bool) -> Word {
+        self.Status.Carry = (value & 0x0001) > 0;
+        let result = value >> 1;
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn rotate_left(&mut self, value: Word, wide: bool) -> Word {
+        let sign = if wide { 0x8000 } else { 0x80 };
+        let result = (value << 1) | self.Status.Carry as Word;
+        self.Status.Carry = (value & sign) > 0;
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn rotate_right(&mut self, value: Word, wide: bool) -> Word {
+        let sign = if wide { 0x8000 } else { 0x80 };
+        let mut result = value >> 1;
+        if self.Status.Carry {
+            result |= sign;
+        }
+        self.Status.Carry = (value & 0x0001) > 0;
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn increment(&mut self, value: Word, wide: bool) -> Word {
+        let result = value.wrapping_add(1);
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn decrement(&mut self, value: Word, wide: bool) -> Word {
+        let result = value.wrapping_sub(1);
+        self.set_flags_NZ(result, wide);
+        result
+    }
+
+    fn test_and_reset_bits(&mut self, value: Word, _wide: bool) -> Word {
+        self.Status.Zero = (self.accumulator() & value) == 0;
+        value & !self.accumulator()
+    }
+
+    fn test_and_set_bits(&mut self, value: Word, _wide: bool) -> Word {
+        self.Status.Zero = (self.accumulator() & value) == 0;
+        value | self.accumulator()
+    }
+
+    fn branch<B: LongBus>(&mut self, mem: &mut B, condition: bool) {
+        let offset = self.fetch_byte(mem) as i8;
+        if condition {
+            let target = self.PC.wrapping_add(offset as Word);
+            // crossing a page only costs extra in emulation mode
+            let crossed = self.Emulation && ((target ^ self.PC) & 0xFF00) != 0;
+            self.extra_cycles += 1 + crossed as Byte;
+            self.PC = target;
+        }
+    }
+
+    fn handle_ORA<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.set_accumulator(self.accumulator() | value);
+    }
+
+    fn handle_AND<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.set_accumulator(self.accumulator() & value);
+    }
+
+    fn handle_EOR<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.set_accumulator(self.accumulator() ^ value);
+    }
+
+    fn handle_ADC<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.add_with_carry(value);
+    }
+
+    fn handle_SBC<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.subtract_with_carry(value);
+    }
+
+    fn handle_CMP<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.accumulator_is_8bit();
+        let value = self.read_operand(mem, mode, wide);
+        self.compare(self.accumulator(), value, wide);
+    }
+
+    fn handle_CPX<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        let value = self.read_operand(mem, mode, wide);
+        self.compare(self.X, value, wide);
+    }
+
+    fn handle_CPY<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        let value = self.read_operand(mem, mode, wide);
+        self.compare(self.Y, value, wide);
+    }
+
+    fn handle_BIT<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.accumulator_is_8bit();
+        let value = self.read_operand(mem, mode, wide);
+        self.Status.Zero = (self.accumulator() & value) == 0;
+        // the immediate form has no memory operand to take N and V from
+        if mode != Mode::IMM {
+            let sign = if wide { 0x8000 } else { 0x80 };
+            self.Status.Negative = (value & sign) > 0;
+            self.Status.Overflow = (value & (sign >> 1)) > 0;
+        }
+    }
+
+    fn handle_LDA<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let value = self.read_operand(mem, mode, !self.accumulator_is_8bit());
+        self.set_accumulator(value);
+    }
+
+    fn handle_LDX<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        self.X = self.read_operand(mem, mode, wide);
+        self.set_flags_NZ(self.X, wide);
+    }
+
+    fn handle_LDY<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        self.Y = self.read_operand(mem, mode, wide);
+        self.set_flags_NZ(self.Y, wide);
+    }
+
+    fn handle_STA<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.accumulator_is_8bit();
+        self.write_operand(mem, mode, self.A, wide);
+    }
+
+    fn handle_STX<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        self.write_operand(mem, mode, self.X, wide);
+    }
+
+    fn handle_STY<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.index_is_8bit();
+        self.write_operand(mem, mode, self.Y, wide);
+    }
+
+    fn handle_STZ<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        let wide = !self.accumulator_is_8bit();
+        self.write_operand(mem, mode, 0, wide);
+    }
+
+    fn handle_ASL<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::shift_left);
+    }
+
+    fn handle_LSR<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::shift_right);
+    }
+
+    fn handle_ROL<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::rotate_left);
+    }
+
+    fn handle_ROR<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::rotate_right);
+    }
+
+    fn handle_INC<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::increment);
+    }
+
+    fn handle_DEC<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::decrement);
+    }
+
+    fn handle_TRB<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::test_and_reset_bits);
+    }
+
+    fn handle_TSB<B: LongBus>(&mut self, mem: &mut B, mode: Mode) {
+        self.read_modify_write(mem, mode, CPU816::test_and_set_bits);
+    }
+
+    fn handle_INX_IMP(&mut self) {
+        self.X = self.set_index(self.X.wrapping_add(1));
+        self.set_flags_NZ(self.X, !self.index_is_8bit());
+    }
+
+    fn handle_INY_IMP(&mut self) {
+        self.Y = self.set_index(self.Y.wrapping_add(1));
+        self.set_flags_NZ(self.Y, !self.index_is_8bit());
+    }
+
+    fn handle_DEX_IMP(&mut self) {
+        self.X = self.set_index(self.X.wrapping_sub(1));
+        self.set_flags_NZ(self.X, !self.index_is_8bit());
+    }
+
+    fn handle_DEY_IMP(&mut self) {
+        self.Y = self.set_index(self.Y.wrapping_sub(1));
+        self.set_flags_NZ(self.Y, !self.index_is_8bit());
+    }
+
+    fn handle_TAX_IMP(&mut self) {
+        // index width decides how much of C is copied
+        self.X = self.set_index(self.A);
+        self.set_flags_NZ(self.X, !self.index_is_8bit());
+    }
+
+    fn handle_TAY_IMP(&mut self) {
+        self.Y = self.set_index(self.A);
+        self.set_flags_NZ(self.Y, !self.index_is_8bit());
+    }
+
+    fn handle_TXA_IMP(&mut self) {
+        self.set_accumulator(self.X);
+    }
+
+    fn handle_TYA_IMP(&mut self) {
+        self.set_accumulator(self.Y);
+    }
+
+    fn handle_TXY_IMP(&mut self) {
+        self.Y = self.X;
+        self.set_flags_NZ(self.Y, !self.index_is_8bit());
+    }
+
+    fn handle_TYX_IMP(&mut self) {
+        self.X = self.Y;
+        self.set_flags_NZ(self.X, !self.index_is_8bit());
+    }
+
+    fn handle_TSX_IMP(&mut self) {
+        self.X = self.set_index(self.SP);
+        self.set_flags_NZ(self.X, !self.index_is_8bit());
+    }
+
+    fn handle_TXS_IMP(&mut self) {
+        self.SP = if self.Emulation {
+            0x0100 | (self.X & 0x00FF)
+        } else {
+            self.X
+        };
+    }
+
+    fn handle_TCS_IMP(&mut self) {
+        self.SP = if self.Emulation {
+            0x0100 | (self.A & 0x00FF)
+        } else {
+            self.A
+        };
+    }
+
+    fn handle_TSC_IMP(&mut self) {
+        self.A = self.SP;
+        self.set_flags_NZ(self.A, true);
+    }
+
+    fn handle_TCD_IMP(&mut self) {
+        self.D = self.A;
+        self.set_flags_NZ(self.D, true);
+    }
+
+    fn handle_TDC_IMP(&mut self) {
+        self.A = self.D;
+        self.set_flags_NZ(self.A, true);
+    }
+
+    fn handle_XBA_IMP(&mut self) {
+        self.A = self.A.rotate_left(8);
+        // flags always reflect the new 8-bit A
+        self.set_flags_NZ(self.A, false);
+    }
+
+    fn handle_XCE_IMP(&mut self) {
+        std::mem::swap(&mut self.Status.Carry, &mut self.Emulation);
+        if self.Emulation {
+            self.SP = 0x0100 | (self.SP & 0x00FF);
+        }
+        self.update_register_widths();
+    }
+
+    fn handle_REP_IMM<B: LongBus>(&mut self, mem: &mut B) {
+        let mask = self.fetch_byte(mem);
+        let status = self.Status.to_byte() & !mask;
+        self.Status.set_from_byte(status);
+        self.update_register_widths();
+    }
+
+    fn handle_SEP_IMM<B: LongBus>(&mut self, mem: &mut B) {
+        let mask = self.fetch_byte(mem);
+        let status = self.Status.to_byte() | mask;
+        self.Status.set_from_byte(status);
+        self.update_register_widths();
+    }
+
+    fn handle_PHA_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        if self.accumulator_is_8bit() {
+            self.push_byte(mem, self.A as Byte);
+        } else {
+            self.extra_cycles += 1;
+            self.push_word(mem, self.A);
+        }
+    }
+
+    fn handle_PHX_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        if self.index_is_8bit() {
+            self.push_byte(mem, self.X as Byte);
+        } else {
+            self.extra_cycles += 1;
+            self.push_word(mem, self.X);
+        }
+    }
+
+    fn handle_PHY_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        if self.index_is_8bit() {
+            self.push_byte(mem, self.Y as Byte);
+        } else {
+            self.extra_cycles += 1;
+            self.push_word(mem, self.Y);
+        }
+    }
+
+    fn handle_PLA_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let value = if self.accumulator_is_8bit() {
+            self.pull_byte(mem) as Word
+        } else {
+            self.extra_cycles += 1;
+            self.pull_word(mem)
+        };
+        self.set_accumulator(value);
+    }
+
+    fn handle_PLX_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let wide = !self.index_is_8bit();
+        self.X = if wide {
+            self.extra_cycles += 1;
+            self.pull_word(mem)
+        } else {
+            self.pull_byte(mem) as Word
+        };
+        self.set_flags_NZ(self.X, wide);
+    }
+
+    fn handle_PLY_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let wide = !self.index_is_8bit();
+        self.Y = if wide {
+            self.extra_cycles += 1;
+            self.pull_word(mem)
+        } else {
+            self.pull_byte(mem) as Word
+        };
+        self.set_flags_NZ(self.Y, wide);
+    }
+
+    fn handle_PHP_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let status = self.status_byte(true);
+        self.push_byte(mem, status);
+    }
+
+    fn handle_PLP_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let status = self.pull_byte(mem);
+        self.Status.set_from_byte(status);
+        self.update_register_widths();
+    }
+
+    fn handle_PHB_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.push_byte(mem, self.DBR);
+    }
+
+    fn handle_PLB_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.DBR = self.pull_byte(mem);
+        self.set_flags_NZ(self.DBR as Word, false);
+    }
+
+    fn handle_PHD_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.push_word_unwrapped(mem, self.D);
+        self.confine_stack();
+    }
+
+    fn handle_PLD_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.D = self.pull_word_unwrapped(mem);
+        self.confine_stack();
+        self.set_flags_NZ(self.D, true);
+    }
+
+    fn handle_PHK_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.push_byte(mem, self.PBR);
+    }
+
+    fn handle_PEA_ABS<B: LongBus>(&mut self, mem: &mut B) {
+        let value = self.fetch_word(mem);
+        self.push_word_unwrapped(mem, value);
+        self.confine_stack();
+    }
+
+    fn handle_PEI_IND<B: LongBus>(&mut self, mem: &mut B) {
+        let offset = self.fetch_direct_offset(mem);
+        let value = self.read_direct_word(mem, offset);
+        self.push_word_unwrapped(mem, value);
+        self.confine_stack();
+    }
+
+    fn handle_PER_REL<B: LongBus>(&mut self, mem: &mut B) {
+        let offset = self.fetch_word(mem);
+        self.push_word_unwrapped(mem, self.PC.wrapping_add(offset));
+        self.confine_stack();
+    }
+
+    fn handle_BRL_REL<B: LongBus>(&mut self, mem: &mut B) {
+        let offset = self.fetch_word(mem);
+        self.PC = self.PC.wrapping_add(offset);
+    }
+
+    fn handle_JMP_ABS<B: LongBus>(&mut self, mem: &mut B) {
+        self.PC = self.fetch_word(mem);
+    }
+
+    fn handle_JMP_IND<B: LongBus>(&mut self, mem: &mut B) {
+        let address = self.fetch_word(mem);
+        self.PC = self.read_bank0_word(mem, address);
+    }
+
+    fn handle_JMP_INDX<B: LongBus>(&mut self, mem: &mut B) {
+        // the pointer lives in the program bank
+        let address = self.fetch_word(mem).wrapping_add(self.X);
+        let lo = self.read_byte(mem, CPU816::long_address(self.PBR, address));
+        let hi = self.read_byte(mem, CPU816::long_address(self.PBR, address.wrapping_add(1)));
+        self.PC = ((hi as u16) << 8) | lo as u16;
+    }
+
+    fn handle_JML_ABSL<B: LongBus>(&mut self, mem: &mut B) {
+        let address = self.fetch_long(mem);
+        self.PBR = (address >> 16) as Byte;
+        self.PC = address as Word;
+    }
+
+    fn handle_JML_INDL<B: LongBus>(&mut self, mem: &mut B) {
+        let pointer = self.fetch_word(mem);
+        self.PC = self.read_bank0_word(mem, pointer);
+        self.PBR = self.read_byte(mem, pointer.wrapping_add(2) as DoubleWord);
+    }
+
+    fn handle_JSR_ABS<B: LongBus>(&mut self, mem: &mut B) {
+        let address = self.fetch_word(mem);
+        self.push_word(mem, self.PC.wrapping_sub(1));
+        self.PC = address;
+    }
+
+    fn handle_JSR_INDX<B: LongBus>(&mut self, mem: &mut B) {
+        let address = self.fetch_word(mem).wrapping_add(self.X);
+        self.push_word(mem, self.PC.wrapping_sub(1));
+        let lo = self.read_byte(mem, CPU816::long_address(self.PBR, address));
+        let hi = self.read_byte(mem, CPU816::long_address(self.PBR, address.wrapping_add(1)));
+        self.PC = ((hi as u16) << 8) | lo as u16;
+    }
+
+    fn handle_JSL_ABSL<B: LongBus>(&mut self, mem: &mut B) {
+        let address = self.fetch_long(mem);
+        self.push_byte_unwrapped(mem, self.PBR);
+        self.push_word_unwrapped(mem, self.PC.wrapping_sub(1));
+        self.confine_stack();
+        self.PBR = (address >> 16) as Byte;
+        self.PC = address as Word;
+    }
+
+    fn handle_RTS_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.PC = self.pull_word(mem).wrapping_add(1);
+    }
+
+    fn handle_RTL_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.PC = self.pull_word_unwrapped(mem).wrapping_add(1);
+        self.PBR = self.pull_byte_unwrapped(mem);
+        self.confine_stack();
+    }
+
+    fn handle_RTI_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        let status = self.pull_byte(mem);
+        self.Status.set_from_byte(status);
+        self.update_register_widths();
+        self.PC = self.pull_word(mem);
+        if !self.Emulation {
+            self.PBR = self.pull_byte(mem);
+            self.extra_cycles += 1;
+        }
+    }
+
+    fn handle_BRK_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.fetch_byte(mem);
+        self.interrupt(mem, NATIVE_BRK_VECTOR, EMULATION_IRQ_VECTOR, true);
+    }
+
+    fn handle_COP_IMP<B: LongBus>(&mut self, mem: &mut B) {
+        self.fetch_byte(mem);
+        self.interrupt(mem, NATIVE_COP_VECTOR, EMULATION_COP_VECTOR, false);
+    }
+
+    // Moves one byte per execution and rewinds PC until C underflows, so the
+    // move can be interrupted like on hardware
+    fn block_move<B: LongBus>(&mut self, mem: &mut B, step: Word) {
+        let destination = self.fetch_byte(mem);
+        let source = self.fetch_byte(mem);
+        self.DBR = destination;
+        let value = self.read_byte(mem, CPU816::long_address(source, self.X));
+        self.write_byte(mem, CPU816::long_address(destination, self.Y), value);
+        self.X = self.set_index(self.X.wrapping_add(step));
+        self.Y = self.set_index(self.Y.wrapping_add(step));
+        self.A = self.A.wrapping_sub(1);
+        if self.A != 0xFFFF {
+            self.PC = self.PC.wrapping_sub(3);
+        }
+    }
+
+    fn handle_MVN_BLK<B: LongBus>(&mut self, mem: &mut B) {
+        self.block_move(mem, 1);
+    }
+
+    fn handle_MVP_BLK<B: LongBus>(&mut self, mem: &mut B) {
+        self.block_move(mem, 0xFFFF);
+    }
+}
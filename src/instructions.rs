@@ -10,6 +10,13 @@ pub mod LDA {
     pub const ABSY: Byte = 0xB9;
     pub const INDX: Byte = 0xA1;
     pub const INDY: Byte = 0xB1;
+    pub const IND: Byte = 0xB2;
+    pub const SR: Byte = 0xA3;
+    pub const INDL: Byte = 0xA7;
+    pub const ABSL: Byte = 0xAF;
+    pub const SRIY: Byte = 0xB3;
+    pub const INDLY: Byte = 0xB7;
+    pub const ABSLX: Byte = 0xBF;
 }
 #[allow(unused, non_snake_case)]
 pub mod LDX {
@@ -37,6 +44,7 @@ pub mod JMP {
 
     pub const ABS: Byte = 0x4C;
     pub const IND: Byte = 0x6C;
+    pub const INDX: Byte = 0x7C;
 }
 
 #[allow(unused, non_snake_case)]
@@ -52,3 +60,1200 @@ pub mod INY {
 
     pub const IMP: Byte = 0xC8;
 }
+
+#[allow(unused, non_snake_case)]
+pub mod STA {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x85;
+    pub const ZPX: Byte = 0x95;
+    pub const ABS: Byte = 0x8D;
+    pub const ABSX: Byte = 0x9D;
+    pub const ABSY: Byte = 0x99;
+    pub const INDX: Byte = 0x81;
+    pub const INDY: Byte = 0x91;
+    pub const IND: Byte = 0x92;
+    pub const SR: Byte = 0x83;
+    pub const INDL: Byte = 0x87;
+    pub const ABSL: Byte = 0x8F;
+    pub const SRIY: Byte = 0x93;
+    pub const INDLY: Byte = 0x97;
+    pub const ABSLX: Byte = 0x9F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod STX {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x86;
+    pub const ZPY: Byte = 0x96;
+    pub const ABS: Byte = 0x8E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod STY {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x84;
+    pub const ZPX: Byte = 0x94;
+    pub const ABS: Byte = 0x8C;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ADC {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x69;
+    pub const ZP: Byte = 0x65;
+    pub const ZPX: Byte = 0x75;
+    pub const ABS: Byte = 0x6D;
+    pub const ABSX: Byte = 0x7D;
+    pub const ABSY: Byte = 0x79;
+    pub const INDX: Byte = 0x61;
+    pub const INDY: Byte = 0x71;
+    pub const IND: Byte = 0x72;
+    pub const SR: Byte = 0x63;
+    pub const INDL: Byte = 0x67;
+    pub const ABSL: Byte = 0x6F;
+    pub const SRIY: Byte = 0x73;
+    pub const INDLY: Byte = 0x77;
+    pub const ABSLX: Byte = 0x7F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SBC {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xE9;
+    pub const ZP: Byte = 0xE5;
+    pub const ZPX: Byte = 0xF5;
+    pub const ABS: Byte = 0xED;
+    pub const ABSX: Byte = 0xFD;
+    pub const ABSY: Byte = 0xF9;
+    pub const INDX: Byte = 0xE1;
+    pub const INDY: Byte = 0xF1;
+    pub const IMM_EB: Byte = 0xEB;
+    pub const IND: Byte = 0xF2;
+    pub const SR: Byte = 0xE3;
+    pub const INDL: Byte = 0xE7;
+    pub const ABSL: Byte = 0xEF;
+    pub const SRIY: Byte = 0xF3;
+    pub const INDLY: Byte = 0xF7;
+    pub const ABSLX: Byte = 0xFF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod AND {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x29;
+    pub const ZP: Byte = 0x25;
+    pub const ZPX: Byte = 0x35;
+    pub const ABS: Byte = 0x2D;
+    pub const ABSX: Byte = 0x3D;
+    pub const ABSY: Byte = 0x39;
+    pub const INDX: Byte = 0x21;
+    pub const INDY: Byte = 0x31;
+    pub const IND: Byte = 0x32;
+    pub const SR: Byte = 0x23;
+    pub const INDL: Byte = 0x27;
+    pub const ABSL: Byte = 0x2F;
+    pub const SRIY: Byte = 0x33;
+    pub const INDLY: Byte = 0x37;
+    pub const ABSLX: Byte = 0x3F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod EOR {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x49;
+    pub const ZP: Byte = 0x45;
+    pub const ZPX: Byte = 0x55;
+    pub const ABS: Byte = 0x4D;
+    pub const ABSX: Byte = 0x5D;
+    pub const ABSY: Byte = 0x59;
+    pub const INDX: Byte = 0x41;
+    pub const INDY: Byte = 0x51;
+    pub const IND: Byte = 0x52;
+    pub const SR: Byte = 0x43;
+    pub const INDL: Byte = 0x47;
+    pub const ABSL: Byte = 0x4F;
+    pub const SRIY: Byte = 0x53;
+    pub const INDLY: Byte = 0x57;
+    pub const ABSLX: Byte = 0x5F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ORA {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x09;
+    pub const ZP: Byte = 0x05;
+    pub const ZPX: Byte = 0x15;
+    pub const ABS: Byte = 0x0D;
+    pub const ABSX: Byte = 0x1D;
+    pub const ABSY: Byte = 0x19;
+    pub const INDX: Byte = 0x01;
+    pub const INDY: Byte = 0x11;
+    pub const IND: Byte = 0x12;
+    pub const SR: Byte = 0x03;
+    pub const INDL: Byte = 0x07;
+    pub const ABSL: Byte = 0x0F;
+    pub const SRIY: Byte = 0x13;
+    pub const INDLY: Byte = 0x17;
+    pub const ABSLX: Byte = 0x1F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BIT {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x24;
+    pub const ABS: Byte = 0x2C;
+    pub const IMM: Byte = 0x89;
+    pub const ZPX: Byte = 0x34;
+    pub const ABSX: Byte = 0x3C;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ASL {
+    use crate::Byte;
+
+    pub const ACC: Byte = 0x0A;
+    pub const ZP: Byte = 0x06;
+    pub const ZPX: Byte = 0x16;
+    pub const ABS: Byte = 0x0E;
+    pub const ABSX: Byte = 0x1E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod LSR {
+    use crate::Byte;
+
+    pub const ACC: Byte = 0x4A;
+    pub const ZP: Byte = 0x46;
+    pub const ZPX: Byte = 0x56;
+    pub const ABS: Byte = 0x4E;
+    pub const ABSX: Byte = 0x5E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ROL {
+    use crate::Byte;
+
+    pub const ACC: Byte = 0x2A;
+    pub const ZP: Byte = 0x26;
+    pub const ZPX: Byte = 0x36;
+    pub const ABS: Byte = 0x2E;
+    pub const ABSX: Byte = 0x3E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ROR {
+    use crate::Byte;
+
+    pub const ACC: Byte = 0x6A;
+    pub const ZP: Byte = 0x66;
+    pub const ZPX: Byte = 0x76;
+    pub const ABS: Byte = 0x6E;
+    pub const ABSX: Byte = 0x7E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CMP {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xC9;
+    pub const ZP: Byte = 0xC5;
+    pub const ZPX: Byte = 0xD5;
+    pub const ABS: Byte = 0xCD;
+    pub const ABSX: Byte = 0xDD;
+    pub const ABSY: Byte = 0xD9;
+    pub const INDX: Byte = 0xC1;
+    pub const INDY: Byte = 0xD1;
+    pub const IND: Byte = 0xD2;
+    pub const SR: Byte = 0xC3;
+    pub const INDL: Byte = 0xC7;
+    pub const ABSL: Byte = 0xCF;
+    pub const SRIY: Byte = 0xD3;
+    pub const INDLY: Byte = 0xD7;
+    pub const ABSLX: Byte = 0xDF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CPX {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xE0;
+    pub const ZP: Byte = 0xE4;
+    pub const ABS: Byte = 0xEC;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CPY {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xC0;
+    pub const ZP: Byte = 0xC4;
+    pub const ABS: Byte = 0xCC;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BCC {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x90;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BCS {
+    use crate::Byte;
+
+    pub const REL: Byte = 0xB0;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BEQ {
+    use crate::Byte;
+
+    pub const REL: Byte = 0xF0;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BNE {
+    use crate::Byte;
+
+    pub const REL: Byte = 0xD0;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BMI {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x30;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BPL {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x10;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BVC {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x50;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BVS {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x70;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHA {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x48;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHP {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x08;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLA {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x68;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLP {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x28;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod JSR {
+    use crate::Byte;
+
+    pub const ABS: Byte = 0x20;
+    pub const INDX: Byte = 0xFC;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RTS {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x60;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RTI {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x40;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BRK {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x00;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TAX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xAA;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TAY {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xA8;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TXA {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x8A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TYA {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x98;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TSX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xBA;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TXS {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x9A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod DEX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xCA;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod DEY {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x88;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod INC {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xE6;
+    pub const ZPX: Byte = 0xF6;
+    pub const ABS: Byte = 0xEE;
+    pub const ABSX: Byte = 0xFE;
+    pub const ACC: Byte = 0x1A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod DEC {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xC6;
+    pub const ZPX: Byte = 0xD6;
+    pub const ABS: Byte = 0xCE;
+    pub const ABSX: Byte = 0xDE;
+    pub const ACC: Byte = 0x3A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CLC {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x18;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SEC {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x38;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CLI {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x58;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SEI {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x78;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CLV {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xB8;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod CLD {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xD8;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SED {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xF8;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod NOP {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xEA;
+    pub const IMP_1A: Byte = 0x1A;
+    pub const IMP_3A: Byte = 0x3A;
+    pub const IMP_5A: Byte = 0x5A;
+    pub const IMP_7A: Byte = 0x7A;
+    pub const IMP_DA: Byte = 0xDA;
+    pub const IMP_FA: Byte = 0xFA;
+    pub const IMM_80: Byte = 0x80;
+    pub const IMM_82: Byte = 0x82;
+    pub const IMM_89: Byte = 0x89;
+    pub const IMM_C2: Byte = 0xC2;
+    pub const IMM_E2: Byte = 0xE2;
+    pub const ZP_04: Byte = 0x04;
+    pub const ZP_44: Byte = 0x44;
+    pub const ZP_64: Byte = 0x64;
+    pub const ZPX_14: Byte = 0x14;
+    pub const ZPX_34: Byte = 0x34;
+    pub const ZPX_54: Byte = 0x54;
+    pub const ZPX_74: Byte = 0x74;
+    pub const ZPX_D4: Byte = 0xD4;
+    pub const ZPX_F4: Byte = 0xF4;
+    pub const ABS_0C: Byte = 0x0C;
+    pub const ABSX_1C: Byte = 0x1C;
+    pub const ABSX_3C: Byte = 0x3C;
+    pub const ABSX_5C: Byte = 0x5C;
+    pub const ABSX_7C: Byte = 0x7C;
+    pub const ABSX_DC: Byte = 0xDC;
+    pub const ABSX_FC: Byte = 0xFC;
+}
+
+// Undocumented NMOS opcodes
+
+#[allow(unused, non_snake_case)]
+pub mod LAX {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xA7;
+    pub const ZPY: Byte = 0xB7;
+    pub const ABS: Byte = 0xAF;
+    pub const ABSY: Byte = 0xBF;
+    pub const INDX: Byte = 0xA3;
+    pub const INDY: Byte = 0xB3;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SAX {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x87;
+    pub const ZPY: Byte = 0x97;
+    pub const ABS: Byte = 0x8F;
+    pub const INDX: Byte = 0x83;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod DCP {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xC7;
+    pub const ZPX: Byte = 0xD7;
+    pub const ABS: Byte = 0xCF;
+    pub const ABSX: Byte = 0xDF;
+    pub const ABSY: Byte = 0xDB;
+    pub const INDX: Byte = 0xC3;
+    pub const INDY: Byte = 0xD3;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ISC {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xE7;
+    pub const ZPX: Byte = 0xF7;
+    pub const ABS: Byte = 0xEF;
+    pub const ABSX: Byte = 0xFF;
+    pub const ABSY: Byte = 0xFB;
+    pub const INDX: Byte = 0xE3;
+    pub const INDY: Byte = 0xF3;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SLO {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x07;
+    pub const ZPX: Byte = 0x17;
+    pub const ABS: Byte = 0x0F;
+    pub const ABSX: Byte = 0x1F;
+    pub const ABSY: Byte = 0x1B;
+    pub const INDX: Byte = 0x03;
+    pub const INDY: Byte = 0x13;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RLA {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x27;
+    pub const ZPX: Byte = 0x37;
+    pub const ABS: Byte = 0x2F;
+    pub const ABSX: Byte = 0x3F;
+    pub const ABSY: Byte = 0x3B;
+    pub const INDX: Byte = 0x23;
+    pub const INDY: Byte = 0x33;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SRE {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x47;
+    pub const ZPX: Byte = 0x57;
+    pub const ABS: Byte = 0x4F;
+    pub const ABSX: Byte = 0x5F;
+    pub const ABSY: Byte = 0x5B;
+    pub const INDX: Byte = 0x43;
+    pub const INDY: Byte = 0x53;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RRA {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x67;
+    pub const ZPX: Byte = 0x77;
+    pub const ABS: Byte = 0x6F;
+    pub const ABSX: Byte = 0x7F;
+    pub const ABSY: Byte = 0x7B;
+    pub const INDX: Byte = 0x63;
+    pub const INDY: Byte = 0x73;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ANC {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x0B;
+    pub const IMM_2B: Byte = 0x2B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ALR {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x4B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ARR {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x6B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SBX {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xCB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SHA {
+    use crate::Byte;
+
+    pub const ABSY: Byte = 0x9F;
+    pub const INDY: Byte = 0x93;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SHX {
+    use crate::Byte;
+
+    pub const ABSY: Byte = 0x9E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SHY {
+    use crate::Byte;
+
+    pub const ABSX: Byte = 0x9C;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TAS {
+    use crate::Byte;
+
+    pub const ABSY: Byte = 0x9B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod LAS {
+    use crate::Byte;
+
+    pub const ABSY: Byte = 0xBB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod ANE {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x8B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod LXA {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xAB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod JAM {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x02;
+    pub const IMP_12: Byte = 0x12;
+    pub const IMP_22: Byte = 0x22;
+    pub const IMP_32: Byte = 0x32;
+    pub const IMP_42: Byte = 0x42;
+    pub const IMP_52: Byte = 0x52;
+    pub const IMP_62: Byte = 0x62;
+    pub const IMP_72: Byte = 0x72;
+    pub const IMP_92: Byte = 0x92;
+    pub const IMP_B2: Byte = 0xB2;
+    pub const IMP_D2: Byte = 0xD2;
+    pub const IMP_F2: Byte = 0xF2;
+}
+
+// 65C02 additions
+
+#[allow(unused, non_snake_case)]
+pub mod BRA {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x80;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xDA;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHY {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x5A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xFA;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLY {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x7A;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod STZ {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x64;
+    pub const ZPX: Byte = 0x74;
+    pub const ABS: Byte = 0x9C;
+    pub const ABSX: Byte = 0x9E;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TRB {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x14;
+    pub const ABS: Byte = 0x1C;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TSB {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x04;
+    pub const ABS: Byte = 0x0C;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod WAI {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xCB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod STP {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xDB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB0 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x07;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB1 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x17;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB2 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x27;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB3 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x37;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB4 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x47;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB5 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x57;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB6 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x67;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RMB7 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x77;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB0 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x87;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB1 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0x97;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB2 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xA7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB3 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xB7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB4 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xC7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB5 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xD7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB6 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xE7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SMB7 {
+    use crate::Byte;
+
+    pub const ZP: Byte = 0xF7;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR0 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x0F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR1 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x1F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR2 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x2F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR3 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x3F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR4 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x4F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR5 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x5F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR6 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x6F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBR7 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x7F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS0 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x8F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS1 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0x9F;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS2 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xAF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS3 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xBF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS4 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xCF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS5 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xDF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS6 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xEF;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod BBS7 {
+    use crate::Byte;
+
+    pub const ZPREL: Byte = 0xFF;
+}
+
+// 65C816 additions
+
+#[allow(unused, non_snake_case)]
+pub mod BRL {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x82;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod COP {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x02;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod JML {
+    use crate::Byte;
+
+    pub const ABSL: Byte = 0x5C;
+    pub const INDL: Byte = 0xDC;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod JSL {
+    use crate::Byte;
+
+    pub const ABSL: Byte = 0x22;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod RTL {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x6B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod MVN {
+    use crate::Byte;
+
+    pub const BLK: Byte = 0x54;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod MVP {
+    use crate::Byte;
+
+    pub const BLK: Byte = 0x44;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PEA {
+    use crate::Byte;
+
+    pub const ABS: Byte = 0xF4;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PEI {
+    use crate::Byte;
+
+    pub const IND: Byte = 0xD4;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PER {
+    use crate::Byte;
+
+    pub const REL: Byte = 0x62;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHB {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x8B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHD {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x0B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PHK {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x4B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLB {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xAB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod PLD {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x2B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod REP {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xC2;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod SEP {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0xE2;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TCD {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x5B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TCS {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x1B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TDC {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x7B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TSC {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x3B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TXY {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0x9B;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod TYX {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xBB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod WDM {
+    use crate::Byte;
+
+    pub const IMM: Byte = 0x42;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod XBA {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xEB;
+}
+
+#[allow(unused, non_snake_case)]
+pub mod XCE {
+    use crate::Byte;
+
+    pub const IMP: Byte = 0xFB;
+}
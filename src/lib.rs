@@ -1,10 +1,20 @@
 mod cpu;
+mod cpu816;
 mod instructions;
+mod mapper;
 mod memory;
+mod memory_map;
+pub mod opcodes;
 #[cfg(test)]
 mod test;
 
+pub use cpu::{BusCycle, CpuError, CpuModel, Flags, IllegalOpcodes, Quirks, StepInfo, CPU};
+pub use cpu816::{Flags816, CPU816};
+pub use mapper::{Atari, Cartridge, Mapper, Mmc1, Nrom, Target, UxRom};
+pub use memory::{Bus, LongBus, LongMemory, Memory};
+pub use memory_map::{Device, MapError, MemoryMap, Region};
+
 // http://www.6502.org/users/obelisk/6502/index.html
 type Byte = u8;
 type Word = u16;
-// type DoubleWord = u32;
+type DoubleWord = u32;
@@ -0,0 +1,236 @@
+use crate::memory::Bus;
+use crate::{Byte, Word};
+
+// Where an access to the cartridge window lands, as an offset into the ROM or RAM image
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    Rom(usize),
+    Ram(usize),
+}
+
+// Bank switching logic of a cartridge. Addresses are relative to the start of the
+// window the cartridge is mapped at, as for any Region::Io.
+pub trait Mapper {
+    fn map(&self, address: Word) -> Target;
+    // Sees every access before it is carried out. Most mappers only listen to writes,
+    // the Atari schemes also switch when certain addresses are read.
+    fn access(&mut self, address: Word, write: Option<Byte>);
+}
+
+// ROM and RAM images behind a mapper, ready to go into a MemoryMap as a Region::Io.
+// Offsets past the end of an image wrap, so smaller images appear mirrored.
+pub struct Cartridge<M: Mapper> {
+    mapper: M,
+    rom: Vec<Byte>,
+    ram: Vec<Byte>,
+}
+
+impl<M: Mapper> Cartridge<M> {
+    pub fn new(mapper: M, rom: Vec<Byte>, ram_size: usize) -> Cartridge<M> {
+        Cartridge {
+            mapper,
+            rom,
+            ram: vec![0; ram_size],
+        }
+    }
+
+    pub fn mapper(&self) -> &M {
+        &self.mapper
+    }
+}
+
+impl<M: Mapper> Bus for Cartridge<M> {
+    fn read(&mut self, address: Word) -> Byte {
+        self.mapper.access(address, None);
+        match self.mapper.map(address) {
+            Target::Rom(offset) if !self.rom.is_empty() => self.rom[offset % self.rom.len()],
+            Target::Ram(offset) if !self.ram.is_empty() => self.ram[offset % self.ram.len()],
+            _ => 0,
+        }
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.mapper.access(address, Some(value));
+        if let Target::Ram(offset) = self.mapper.map(address) {
+            if !self.ram.is_empty() {
+                let size = self.ram.len();
+                self.ram[offset % size] = value;
+            }
+        }
+    }
+}
+
+const PRG_BANK_SIZE: usize = 0x4000;
+
+// NES mapper 0, window $8000-$FFFF. 16KB images show up twice.
+pub struct Nrom;
+
+impl Mapper for Nrom {
+    fn map(&self, address: Word) -> Target {
+        Target::Rom(address as usize)
+    }
+
+    fn access(&mut self, _address: Word, _write: Option<Byte>) {}
+}
+
+// NES mapper 2, window $8000-$FFFF. Any write selects the 16KB bank at $8000,
+// $C000 holds the last bank.
+pub struct UxRom {
+    banks: usize,
+    bank: usize,
+}
+
+impl UxRom {
+    pub fn new(banks: usize) -> UxRom {
+        assert!(banks > 0, "UxROM needs at least one PRG bank");
+        UxRom { banks, bank: 0 }
+    }
+}
+
+impl Mapper for UxRom {
+    fn map(&self, address: Word) -> Target {
+        let bank = match address {
+            0x0000..=0x3FFF => self.bank,
+            _ => self.banks - 1,
+        };
+        Target::Rom(bank * PRG_BANK_SIZE + (address as usize & 0x3FFF))
+    }
+
+    fn access(&mut self, _address: Word, write: Option<Byte>) {
+        if let Some(value) = write {
+            self.bank = value as usize % self.banks;
+        }
+    }
+}
+
+// NES mapper 1, window $6000-$FFFF with 8KB of PRG RAM at $6000. Registers are loaded
+// a bit at a time by five writes to $8000-$FFFF; bit 7 set restarts the sequence.
+// A write straight after another write is ignored, so the dummy write of a
+// read-modify-write instruction doesn't count as a bit.
+// Only the PRG side is modelled. The CHR registers are kept for the PPU, which reaches
+// them through MemoryMap::device once the cartridge is mapped.
+#[allow(non_snake_case)]
+pub struct Mmc1 {
+    banks: usize,
+    shift: Byte,
+    writes: u8,
+    last_write: bool,
+    pub Control: Byte,
+    pub ChrBank0: Byte,
+    pub ChrBank1: Byte,
+    pub PrgBank: Byte,
+}
+
+impl Mmc1 {
+    pub fn new(banks: usize) -> Mmc1 {
+        assert!(banks > 0, "MMC1 needs at least one PRG bank");
+        Mmc1 {
+            banks,
+            shift: 0,
+            writes: 0,
+            last_write: false,
+            // powers up with the last bank fixed at $C000
+            Control: 0x0C,
+            ChrBank0: 0,
+            ChrBank1: 0,
+            PrgBank: 0,
+        }
+    }
+}
+
+impl Mapper for Mmc1 {
+    fn map(&self, address: Word) -> Target {
+        let address = address as usize;
+        if address < 0x2000 {
+            return Target::Ram(address);
+        }
+        let address = address - 0x2000;
+        let selected = (self.PrgBank & 0x0F) as usize;
+        let bank = match ((self.Control >> 2) & 0x03, address < PRG_BANK_SIZE) {
+            // 32KB mode ignores the low bit of the bank number
+            (0 | 1, true) => selected & !1,
+            (0 | 1, false) => selected | 1,
+            (2, true) => 0,
+            (2, false) => selected,
+            (_, true) => selected,
+            (_, false) => self.banks - 1,
+        };
+        Target::Rom((bank % self.banks) * PRG_BANK_SIZE + (address & 0x3FFF))
+    }
+
+    fn access(&mut self, address: Word, write: Option<Byte>) {
+        // only the cycles inside the window are seen here
+        let back_to_back = self.last_write && write.is_some();
+        self.last_write = write.is_some();
+        let Some(value) = write else {
+            return;
+        };
+        if address < 0x2000 || back_to_back {
+            return;
+        }
+        if value & 0b1000_0000 > 0 {
+            self.shift = 0;
+            self.writes = 0;
+            self.Control |= 0x0C;
+            return;
+        }
+        self.shift = (self.shift >> 1) | ((value & 0x01) << 4);
+        self.writes += 1;
+        if self.writes < 5 {
+            return;
+        }
+        // the fifth write picks the register from address bits 13 and 14. The window
+        // starts three 8KB pages before $8000, which shifts the numbering by three.
+        match (address >> 13) & 0x03 {
+            1 => self.Control = self.shift,
+            2 => self.ChrBank0 = self.shift,
+            3 => self.ChrBank1 = self.shift,
+            _ => self.PrgBank = self.shift,
+        }
+        self.shift = 0;
+        self.writes = 0;
+    }
+}
+
+// Atari 2600 schemes, window $1000-$1FFF. Reading or writing a hotspot near the
+// top of the window switches the 4KB bank; they start out in the last one.
+pub struct Atari {
+    hotspot: Word,
+    banks: usize,
+    bank: usize,
+}
+
+impl Atari {
+    const BANK_SIZE: usize = 0x1000;
+
+    // 8KB, hotspots at $1FF8-$1FF9
+    pub fn f8() -> Atari {
+        Atari {
+            hotspot: 0x0FF8,
+            banks: 2,
+            bank: 1,
+        }
+    }
+
+    // 16KB, hotspots at $1FF6-$1FF9
+    pub fn f6() -> Atari {
+        Atari {
+            hotspot: 0x0FF6,
+            banks: 4,
+            bank: 3,
+        }
+    }
+}
+
+impl Mapper for Atari {
+    fn map(&self, address: Word) -> Target {
+        Target::Rom(self.bank * Atari::BANK_SIZE + (address as usize & 0x0FFF))
+    }
+
+    fn access(&mut self, address: Word, _write: Option<Byte>) {
+        let address = address & 0x0FFF;
+        if (self.hotspot..self.hotspot + self.banks as Word).contains(&address) {
+            self.bank = (address - self.hotspot) as usize;
+        }
+    }
+}
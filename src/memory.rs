@@ -1,5 +1,13 @@
-use crate::Byte;
+use crate::{Byte, DoubleWord, Word};
 
+// What the CPU sees of the outside world. Reads take `&mut self` since devices
+// may react to being read (clearing a status flag, advancing a FIFO, ...).
+pub trait Bus {
+    fn read(&mut self, address: Word) -> Byte;
+    fn write(&mut self, address: Word, value: Byte);
+}
+
+#[derive(Clone)]
 pub struct Memory {
     pub data: [Byte; 64 * 1024],
 }
@@ -12,3 +20,57 @@ impl Memory {
         }
     }
 }
+
+impl Default for Memory {
+    fn default() -> Memory {
+        Memory::new()
+    }
+}
+
+impl Bus for Memory {
+    fn read(&mut self, address: Word) -> Byte {
+        self.data[address as usize]
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.data[address as usize] = value;
+    }
+}
+
+// The same for the 65C816, which puts out 24-bit addresses
+pub trait LongBus {
+    fn read(&mut self, address: DoubleWord) -> Byte;
+    fn write(&mut self, address: DoubleWord, value: Byte);
+}
+
+// 24-bit address space of the 65C816, 256 banks of 64KB
+pub struct LongMemory {
+    pub data: Vec<Byte>,
+}
+
+#[allow(unused)]
+impl LongMemory {
+    pub const SIZE: DoubleWord = 0x100_0000;
+
+    pub fn new() -> LongMemory {
+        LongMemory {
+            data: vec![0; LongMemory::SIZE as usize],
+        }
+    }
+}
+
+impl LongBus for LongMemory {
+    fn read(&mut self, address: DoubleWord) -> Byte {
+        self.data[address as usize]
+    }
+
+    fn write(&mut self, address: DoubleWord, value: Byte) {
+        self.data[address as usize] = value;
+    }
+}
+
+impl Default for LongMemory {
+    fn default() -> LongMemory {
+        LongMemory::new()
+    }
+}
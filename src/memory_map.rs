@@ -0,0 +1,242 @@
+use crate::memory::{Bus, Memory};
+use crate::{Byte, Word};
+use std::any::Any;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+// What a range of the address space is wired to
+pub enum Region {
+    Ram,
+    Rom(Vec<Byte>), // the image must fill the range, writes are ignored
+    // repeats the region starting at the given address, e.g. 2KB of RAM seen four times
+    Mirror(Word),
+    // memory-mapped I/O, addressed relative to the start of the range
+    Io(Box<dyn Device>),
+    OpenBus, // nothing answers, reads see whatever was last on the data bus
+}
+
+// What can sit in an Io region: any Bus that owns its state, so that
+// MemoryMap::device can hand it back as its own type
+pub trait Device: Bus + Any {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Bus + Any> Device for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapError {
+    Empty { Start: Word, End: Word },
+    Overlap { Start: Word, End: Word, With: Word },
+    Unmapped { Start: Word, End: Word },
+    RomSize { Start: Word, End: Word, Size: usize },
+    MirrorTarget { Start: Word, Target: Word },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MapError::Empty { Start, End } => {
+                write!(f, "range {:04X}-{:04X} is empty", Start, End)
+            }
+            MapError::Overlap { Start, End, With } => write!(
+                f,
+                "range {:04X}-{:04X} overlaps the one starting at {:04X}",
+                Start, End, With
+            ),
+            MapError::Unmapped { Start, End } => {
+                write!(f, "nothing is mapped at {:04X}-{:04X}", Start, End)
+            }
+            MapError::RomSize { Start, End, Size } => write!(
+                f,
+                "ROM image of {} bytes does not fill {:04X}-{:04X}",
+                Size, Start, End
+            ),
+            MapError::MirrorTarget { Start, Target } => write!(
+                f,
+                "mirror at {:04X} points at {:04X}, which starts no RAM, ROM or I/O range",
+                Start, Target
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+enum Kind {
+    Ram,
+    Rom,
+    Mirror { target: Word, period: u32 },
+    Io(Box<dyn Device>),
+    OpenBus,
+}
+
+struct Mapping {
+    start: Word,
+    end: Word,
+    kind: Kind,
+}
+
+// Address decoder in front of a flat Memory that holds the RAM and ROM contents
+pub struct MemoryMap {
+    memory: Memory,
+    mappings: Vec<Mapping>, // sorted and covering the whole address space
+    data_bus: Byte,
+}
+
+impl MemoryMap {
+    pub fn new(regions: Vec<(RangeInclusive<Word>, Region)>) -> Result<MemoryMap, MapError> {
+        let mut regions: Vec<(Word, Word, Region)> = regions
+            .into_iter()
+            .map(|(range, region)| (*range.start(), *range.end(), region))
+            .collect();
+        regions.sort_by_key(|(start, _, _)| *start);
+
+        let mut next: u32 = 0x0000;
+        for (index, (start, end, _)) in regions.iter().enumerate() {
+            if start > end {
+                return Err(MapError::Empty {
+                    Start: *start,
+                    End: *end,
+                });
+            }
+            if (*start as u32) < next {
+                return Err(MapError::Overlap {
+                    Start: *start,
+                    End: *end,
+                    With: regions[index - 1].0,
+                });
+            }
+            if (*start as u32) > next {
+                return Err(MapError::Unmapped {
+                    Start: next as Word,
+                    End: start - 1,
+                });
+            }
+            next = *end as u32 + 1;
+        }
+        if next <= 0xFFFF {
+            return Err(MapError::Unmapped {
+                Start: next as Word,
+                End: 0xFFFF,
+            });
+        }
+
+        // mirrors repeat every `length of the target` bytes
+        let lengths: Vec<(Word, u32, bool)> = regions
+            .iter()
+            .map(|(start, end, region)| {
+                let mirrorable = matches!(region, Region::Ram | Region::Rom(_) | Region::Io(_));
+                (*start, (end - start) as u32 + 1, mirrorable)
+            })
+            .collect();
+
+        let mut memory = Memory::new();
+        let mut mappings = Vec::with_capacity(regions.len());
+        for (start, end, region) in regions {
+            let kind = match region {
+                Region::Ram => Kind::Ram,
+                Region::Rom(image) => {
+                    if image.len() != (end - start) as usize + 1 {
+                        return Err(MapError::RomSize {
+                            Start: start,
+                            End: end,
+                            Size: image.len(),
+                        });
+                    }
+                    memory.data[start as usize..=end as usize].copy_from_slice(&image);
+                    Kind::Rom
+                }
+                Region::Mirror(target) => {
+                    let period = lengths
+                        .iter()
+                        .find(|(other, _, mirrorable)| *other == target && *mirrorable)
+                        .map(|(_, length, _)| *length)
+                        .ok_or(MapError::MirrorTarget {
+                            Start: start,
+                            Target: target,
+                        })?;
+                    Kind::Mirror { target, period }
+                }
+                Region::Io(device) => Kind::Io(device),
+                Region::OpenBus => Kind::OpenBus,
+            };
+            mappings.push(Mapping { start, end, kind });
+        }
+
+        Ok(MemoryMap {
+            memory,
+            mappings,
+            data_bus: 0,
+        })
+    }
+
+    // The device of the Io region answering at `address`, if it is a T
+    pub fn device<T: Device>(&self, address: Word) -> Option<&T> {
+        let (index, _) = self.decode(address);
+        match &self.mappings[index].kind {
+            Kind::Io(device) => device.as_ref().as_any().downcast_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn device_mut<T: Device>(&mut self, address: Word) -> Option<&mut T> {
+        let (index, _) = self.decode(address);
+        match &mut self.mappings[index].kind {
+            Kind::Io(device) => device.as_mut().as_any_mut().downcast_mut(),
+            _ => None,
+        }
+    }
+
+    // Follows a mirror to the address it stands for, returning the mapping that answers
+    fn decode(&self, address: Word) -> (usize, Word) {
+        let index = self.find(address);
+        if let Kind::Mirror { target, period } = self.mappings[index].kind {
+            let offset = (address - self.mappings[index].start) as u32 % period;
+            let address = target + offset as Word;
+            return (self.find(address), address);
+        }
+        (index, address)
+    }
+
+    fn find(&self, address: Word) -> usize {
+        self.mappings
+            .partition_point(|mapping| mapping.end < address)
+    }
+}
+
+impl Bus for MemoryMap {
+    fn read(&mut self, address: Word) -> Byte {
+        let (index, address) = self.decode(address);
+        let mapping = &mut self.mappings[index];
+        let value = match &mut mapping.kind {
+            Kind::Ram | Kind::Rom => self.memory.data[address as usize],
+            Kind::Io(device) => device.read(address - mapping.start),
+            Kind::OpenBus => self.data_bus,
+            Kind::Mirror { .. } => unreachable!("mirrors point at other mappings"),
+        };
+        self.data_bus = value;
+        value
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.data_bus = value;
+        let (index, address) = self.decode(address);
+        let mapping = &mut self.mappings[index];
+        match &mut mapping.kind {
+            Kind::Ram => self.memory.data[address as usize] = value,
+            Kind::Io(device) => device.write(address - mapping.start, value),
+            Kind::Rom | Kind::OpenBus => {}
+            Kind::Mirror { .. } => unreachable!("mirrors point at other mappings"),
+        }
+    }
+}
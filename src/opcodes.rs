@@ -0,0 +1,712 @@
+use crate::cpu::{CpuModel, IllegalOpcodes, Quirks, CPU};
+use crate::memory::Bus;
+use crate::{instructions, Byte};
+use AddressingMode::*;
+use Availability::*;
+use Mnemonic::*;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mnemonic {
+    LDA,
+    LDX,
+    LDY,
+    JMP,
+    INX,
+    INY,
+    STA,
+    STX,
+    STY,
+    ADC,
+    SBC,
+    AND,
+    EOR,
+    ORA,
+    BIT,
+    ASL,
+    LSR,
+    ROL,
+    ROR,
+    CMP,
+    CPX,
+    CPY,
+    BCC,
+    BCS,
+    BEQ,
+    BNE,
+    BMI,
+    BPL,
+    BVC,
+    BVS,
+    PHA,
+    PHP,
+    PLA,
+    PLP,
+    JSR,
+    RTS,
+    RTI,
+    BRK,
+    TAX,
+    TAY,
+    TXA,
+    TYA,
+    TSX,
+    TXS,
+    DEX,
+    DEY,
+    INC,
+    DEC,
+    CLC,
+    SEC,
+    CLI,
+    SEI,
+    CLV,
+    CLD,
+    SED,
+    NOP,
+    // Undocumented NMOS opcodes
+    LAX,
+    SAX,
+    DCP,
+    ISC,
+    SLO,
+    RLA,
+    SRE,
+    RRA,
+    ANC,
+    ALR,
+    ARR,
+    SBX,
+    SHA,
+    SHX,
+    SHY,
+    TAS,
+    LAS,
+    ANE,
+    LXA,
+    JAM,
+    // 65C02 additions
+    BRA,
+    PHX,
+    PHY,
+    PLX,
+    PLY,
+    STZ,
+    TRB,
+    TSB,
+    WAI,
+    STP,
+    RMB0,
+    RMB1,
+    RMB2,
+    RMB3,
+    RMB4,
+    RMB5,
+    RMB6,
+    RMB7,
+    SMB0,
+    SMB1,
+    SMB2,
+    SMB3,
+    SMB4,
+    SMB5,
+    SMB6,
+    SMB7,
+    BBR0,
+    BBR1,
+    BBR2,
+    BBR3,
+    BBR4,
+    BBR5,
+    BBR6,
+    BBR7,
+    BBS0,
+    BBS1,
+    BBS2,
+    BBS3,
+    BBS4,
+    BBS5,
+    BBS6,
+    BBS7,
+}
+
+// Named like the opcode constants in `instructions`. IND is (abs) for JMP and (zp)
+// everywhere else, INDX is (abs,X) for JMP and (zp,X) everywhere else.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressingMode {
+    IMP,
+    ACC,
+    IMM,
+    ZP,
+    ZPX,
+    ZPY,
+    REL,
+    ABS,
+    ABSX,
+    ABSY,
+    IND,
+    INDX,
+    INDY,
+    ZPREL,
+}
+
+// Which parts run an opcode the way its table entry describes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Availability {
+    All,
+    Nmos,            // undocumented opcodes of the NMOS 6502, 2A03 and 6507
+    Cmos,            // 65C02 and 65SC02
+    BitInstructions, // RMB/SMB/BBR/BBS and WAI/STP, 65C02 only
+}
+
+impl Availability {
+    pub fn includes(&self, model: CpuModel) -> bool {
+        let quirks = model.quirks();
+        match self {
+            All => true,
+            Nmos => quirks.IllegalOpcodes == IllegalOpcodes::Undocumented,
+            Cmos => quirks.IllegalOpcodes == IllegalOpcodes::Nop,
+            BitInstructions => quirks.BitInstructions,
+        }
+    }
+}
+
+// Status bits an instruction can change, laid out like the status byte (NV1B DIZC)
+pub mod flags {
+    use crate::Byte;
+
+    pub const NONE: Byte = 0;
+    pub const N: Byte = 0b1000_0000;
+    pub const V: Byte = 0b0100_0000;
+    pub const D: Byte = 0b0000_1000;
+    pub const I: Byte = 0b0000_0100;
+    pub const Z: Byte = 0b0000_0010;
+    pub const C: Byte = 0b0000_0001;
+}
+
+use flags::*;
+
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug)]
+pub struct OpcodeInfo {
+    pub Opcode: Byte,
+    pub Mnemonic: Mnemonic,
+    pub Mode: AddressingMode,
+    pub Bytes: Byte,
+    // page crossings, taken branches and decimal mode add to these
+    pub Cycles: Byte,
+    pub Flags: Byte,
+    pub Availability: Availability,
+    pub(crate) handler: Handler,
+}
+
+// Runs the instruction once its opcode has been fetched
+pub(crate) type Handler = fn(&mut CPU, &mut dyn Bus);
+
+// The handler follows from the other fields, and function pointers don't compare reliably
+impl PartialEq for OpcodeInfo {
+    fn eq(&self, other: &OpcodeInfo) -> bool {
+        self.Opcode == other.Opcode
+            && self.Mnemonic == other.Mnemonic
+            && self.Mode == other.Mode
+            && self.Bytes == other.Bytes
+            && self.Cycles == other.Cycles
+            && self.Flags == other.Flags
+            && self.Availability == other.Availability
+    }
+}
+
+impl Eq for OpcodeInfo {}
+
+// Describes an opcode as the NMOS 6502 runs it
+pub fn opcode_info(opcode: Byte) -> &'static OpcodeInfo {
+    opcode_info_for(CpuModel::Nmos6502, opcode)
+}
+
+// The NMOS and CMOS families give some opcodes different meanings, so the answer depends on the part
+pub fn opcode_info_for(model: CpuModel, opcode: Byte) -> &'static OpcodeInfo {
+    decode(&model.quirks(), opcode)
+}
+
+pub(crate) fn decode(quirks: &Quirks, opcode: Byte) -> &'static OpcodeInfo {
+    let table = match quirks.IllegalOpcodes {
+        IllegalOpcodes::Undocumented => &NMOS_OPCODES,
+        IllegalOpcodes::Nop if quirks.BitInstructions => &CMOS_OPCODES,
+        IllegalOpcodes::Nop => &CMOS_OPCODES_WITHOUT_BIT_INSTRUCTIONS,
+    };
+    &table[opcode as usize]
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn op(
+    opcode: Byte,
+    mnemonic: Mnemonic,
+    mode: AddressingMode,
+    bytes: Byte,
+    cycles: Byte,
+    flags: Byte,
+    availability: Availability,
+    handler: Handler,
+) -> OpcodeInfo {
+    OpcodeInfo {
+        Opcode: opcode,
+        Mnemonic: mnemonic,
+        Mode: mode,
+        Bytes: bytes,
+        Cycles: cycles,
+        Flags: flags,
+        Availability: availability,
+        handler,
+    }
+}
+
+// Places the entries by opcode, refusing to compile unless each opcode is listed exactly
+// once across `entries` and `additions`. `changes` replace entries that are already placed.
+const fn table(
+    entries: &[OpcodeInfo],
+    additions: &[OpcodeInfo],
+    changes: &[OpcodeInfo],
+) -> [OpcodeInfo; 256] {
+    let mut table = [op(0x00, NOP, IMP, 0, 0, NONE, All, CPU::handle_NOP_IMP); 256];
+    let mut i = 0;
+    while i < entries.len() + additions.len() {
+        let entry = if i < entries.len() {
+            entries[i]
+        } else {
+            additions[i - entries.len()]
+        };
+        let slot = entry.Opcode as usize;
+        assert!(table[slot].Bytes == 0, "opcode listed twice");
+        table[slot] = entry;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < changes.len() {
+        let slot = changes[i].Opcode as usize;
+        assert!(table[slot].Bytes != 0, "changed opcode is not listed");
+        table[slot] = changes[i];
+        i += 1;
+    }
+    let mut opcode = 0;
+    while opcode < 256 {
+        assert!(table[opcode].Bytes != 0, "opcode missing");
+        opcode += 1;
+    }
+    table
+}
+
+// The 65SC02 runs the bit instructions as 1 cycle NOPs
+const fn without_bit_instructions(mut table: [OpcodeInfo; 256]) -> [OpcodeInfo; 256] {
+    let mut opcode = 0;
+    while opcode < 256 {
+        if let BitInstructions = table[opcode].Availability {
+            table[opcode] = op(
+                opcode as Byte,
+                NOP,
+                IMP,
+                1,
+                1,
+                NONE,
+                Cmos,
+                CPU::handle_NOP_IMP,
+            );
+        }
+        opcode += 1;
+    }
+    table
+}
+
+// Documented opcodes as the NMOS parts run them, shared by both families
+#[rustfmt::skip]
+const DOCUMENTED: &[OpcodeInfo] = &[
+    op(instructions::LDA::IMM, LDA, IMM, 2, 2, N | Z, All, CPU::handle_LDA_IMM),
+    op(instructions::LDA::ZP, LDA, ZP, 2, 3, N | Z, All, CPU::handle_LDA_ZP),
+    op(instructions::LDA::ZPX, LDA, ZPX, 2, 4, N | Z, All, CPU::handle_LDA_ZPX),
+    op(instructions::LDA::ABS, LDA, ABS, 3, 4, N | Z, All, CPU::handle_LDA_ABS),
+    op(instructions::LDA::ABSX, LDA, ABSX, 3, 4, N | Z, All, CPU::handle_LDA_ABSX),
+    op(instructions::LDA::ABSY, LDA, ABSY, 3, 4, N | Z, All, CPU::handle_LDA_ABSY),
+    op(instructions::LDA::INDX, LDA, INDX, 2, 6, N | Z, All, CPU::handle_LDA_INDX),
+    op(instructions::LDA::INDY, LDA, INDY, 2, 5, N | Z, All, CPU::handle_LDA_INDY),
+    op(instructions::LDX::IMM, LDX, IMM, 2, 2, N | Z, All, CPU::handle_LDX_IMM),
+    op(instructions::LDX::ZP, LDX, ZP, 2, 3, N | Z, All, CPU::handle_LDX_ZP),
+    op(instructions::LDX::ZPY, LDX, ZPY, 2, 4, N | Z, All, CPU::handle_LDX_ZPY),
+    op(instructions::LDX::ABS, LDX, ABS, 3, 4, N | Z, All, CPU::handle_LDX_ABS),
+    op(instructions::LDX::ABSY, LDX, ABSY, 3, 4, N | Z, All, CPU::handle_LDX_ABSY),
+    op(instructions::LDY::IMM, LDY, IMM, 2, 2, N | Z, All, CPU::handle_LDY_IMM),
+    op(instructions::LDY::ZP, LDY, ZP, 2, 3, N | Z, All, CPU::handle_LDY_ZP),
+    op(instructions::LDY::ZPX, LDY, ZPX, 2, 4, N | Z, All, CPU::handle_LDY_ZPX),
+    op(instructions::LDY::ABS, LDY, ABS, 3, 4, N | Z, All, CPU::handle_LDY_ABS),
+    op(instructions::LDY::ABSX, LDY, ABSX, 3, 4, N | Z, All, CPU::handle_LDY_ABSX),
+    op(instructions::JMP::ABS, JMP, ABS, 3, 3, NONE, All, CPU::handle_JMP_ABS),
+    op(instructions::JMP::IND, JMP, IND, 3, 5, NONE, All, CPU::handle_JMP_IND),
+    op(instructions::INX::IMP, INX, IMP, 1, 2, N | Z, All, CPU::handle_INX_IMP),
+    op(instructions::INY::IMP, INY, IMP, 1, 2, N | Z, All, CPU::handle_INY_IMP),
+    op(instructions::STA::ZP, STA, ZP, 2, 3, NONE, All, CPU::handle_STA_ZP),
+    op(instructions::STA::ZPX, STA, ZPX, 2, 4, NONE, All, CPU::handle_STA_ZPX),
+    op(instructions::STA::ABS, STA, ABS, 3, 4, NONE, All, CPU::handle_STA_ABS),
+    op(instructions::STA::ABSX, STA, ABSX, 3, 5, NONE, All, CPU::handle_STA_ABSX),
+    op(instructions::STA::ABSY, STA, ABSY, 3, 5, NONE, All, CPU::handle_STA_ABSY),
+    op(instructions::STA::INDX, STA, INDX, 2, 6, NONE, All, CPU::handle_STA_INDX),
+    op(instructions::STA::INDY, STA, INDY, 2, 6, NONE, All, CPU::handle_STA_INDY),
+    op(instructions::STX::ZP, STX, ZP, 2, 3, NONE, All, CPU::handle_STX_ZP),
+    op(instructions::STX::ZPY, STX, ZPY, 2, 4, NONE, All, CPU::handle_STX_ZPY),
+    op(instructions::STX::ABS, STX, ABS, 3, 4, NONE, All, CPU::handle_STX_ABS),
+    op(instructions::STY::ZP, STY, ZP, 2, 3, NONE, All, CPU::handle_STY_ZP),
+    op(instructions::STY::ZPX, STY, ZPX, 2, 4, NONE, All, CPU::handle_STY_ZPX),
+    op(instructions::STY::ABS, STY, ABS, 3, 4, NONE, All, CPU::handle_STY_ABS),
+    op(instructions::ADC::IMM, ADC, IMM, 2, 2, N | V | Z | C, All, CPU::handle_ADC_IMM),
+    op(instructions::ADC::ZP, ADC, ZP, 2, 3, N | V | Z | C, All, CPU::handle_ADC_ZP),
+    op(instructions::ADC::ZPX, ADC, ZPX, 2, 4, N | V | Z | C, All, CPU::handle_ADC_ZPX),
+    op(instructions::ADC::ABS, ADC, ABS, 3, 4, N | V | Z | C, All, CPU::handle_ADC_ABS),
+    op(instructions::ADC::ABSX, ADC, ABSX, 3, 4, N | V | Z | C, All, CPU::handle_ADC_ABSX),
+    op(instructions::ADC::ABSY, ADC, ABSY, 3, 4, N | V | Z | C, All, CPU::handle_ADC_ABSY),
+    op(instructions::ADC::INDX, ADC, INDX, 2, 6, N | V | Z | C, All, CPU::handle_ADC_INDX),
+    op(instructions::ADC::INDY, ADC, INDY, 2, 5, N | V | Z | C, All, CPU::handle_ADC_INDY),
+    op(instructions::SBC::IMM, SBC, IMM, 2, 2, N | V | Z | C, All, CPU::handle_SBC_IMM),
+    op(instructions::SBC::ZP, SBC, ZP, 2, 3, N | V | Z | C, All, CPU::handle_SBC_ZP),
+    op(instructions::SBC::ZPX, SBC, ZPX, 2, 4, N | V | Z | C, All, CPU::handle_SBC_ZPX),
+    op(instructions::SBC::ABS, SBC, ABS, 3, 4, N | V | Z | C, All, CPU::handle_SBC_ABS),
+    op(instructions::SBC::ABSX, SBC, ABSX, 3, 4, N | V | Z | C, All, CPU::handle_SBC_ABSX),
+    op(instructions::SBC::ABSY, SBC, ABSY, 3, 4, N | V | Z | C, All, CPU::handle_SBC_ABSY),
+    op(instructions::SBC::INDX, SBC, INDX, 2, 6, N | V | Z | C, All, CPU::handle_SBC_INDX),
+    op(instructions::SBC::INDY, SBC, INDY, 2, 5, N | V | Z | C, All, CPU::handle_SBC_INDY),
+    op(instructions::AND::IMM, AND, IMM, 2, 2, N | Z, All, CPU::handle_AND_IMM),
+    op(instructions::AND::ZP, AND, ZP, 2, 3, N | Z, All, CPU::handle_AND_ZP),
+    op(instructions::AND::ZPX, AND, ZPX, 2, 4, N | Z, All, CPU::handle_AND_ZPX),
+    op(instructions::AND::ABS, AND, ABS, 3, 4, N | Z, All, CPU::handle_AND_ABS),
+    op(instructions::AND::ABSX, AND, ABSX, 3, 4, N | Z, All, CPU::handle_AND_ABSX),
+    op(instructions::AND::ABSY, AND, ABSY, 3, 4, N | Z, All, CPU::handle_AND_ABSY),
+    op(instructions::AND::INDX, AND, INDX, 2, 6, N | Z, All, CPU::handle_AND_INDX),
+    op(instructions::AND::INDY, AND, INDY, 2, 5, N | Z, All, CPU::handle_AND_INDY),
+    op(instructions::EOR::IMM, EOR, IMM, 2, 2, N | Z, All, CPU::handle_EOR_IMM),
+    op(instructions::EOR::ZP, EOR, ZP, 2, 3, N | Z, All, CPU::handle_EOR_ZP),
+    op(instructions::EOR::ZPX, EOR, ZPX, 2, 4, N | Z, All, CPU::handle_EOR_ZPX),
+    op(instructions::EOR::ABS, EOR, ABS, 3, 4, N | Z, All, CPU::handle_EOR_ABS),
+    op(instructions::EOR::ABSX, EOR, ABSX, 3, 4, N | Z, All, CPU::handle_EOR_ABSX),
+    op(instructions::EOR::ABSY, EOR, ABSY, 3, 4, N | Z, All, CPU::handle_EOR_ABSY),
+    op(instructions::EOR::INDX, EOR, INDX, 2, 6, N | Z, All, CPU::handle_EOR_INDX),
+    op(instructions::EOR::INDY, EOR, INDY, 2, 5, N | Z, All, CPU::handle_EOR_INDY),
+    op(instructions::ORA::IMM, ORA, IMM, 2, 2, N | Z, All, CPU::handle_ORA_IMM),
+    op(instructions::ORA::ZP, ORA, ZP, 2, 3, N | Z, All, CPU::handle_ORA_ZP),
+    op(instructions::ORA::ZPX, ORA, ZPX, 2, 4, N | Z, All, CPU::handle_ORA_ZPX),
+    op(instructions::ORA::ABS, ORA, ABS, 3, 4, N | Z, All, CPU::handle_ORA_ABS),
+    op(instructions::ORA::ABSX, ORA, ABSX, 3, 4, N | Z, All, CPU::handle_ORA_ABSX),
+    op(instructions::ORA::ABSY, ORA, ABSY, 3, 4, N | Z, All, CPU::handle_ORA_ABSY),
+    op(instructions::ORA::INDX, ORA, INDX, 2, 6, N | Z, All, CPU::handle_ORA_INDX),
+    op(instructions::ORA::INDY, ORA, INDY, 2, 5, N | Z, All, CPU::handle_ORA_INDY),
+    op(instructions::BIT::ZP, BIT, ZP, 2, 3, N | V | Z, All, CPU::handle_BIT_ZP),
+    op(instructions::BIT::ABS, BIT, ABS, 3, 4, N | V | Z, All, CPU::handle_BIT_ABS),
+    op(instructions::ASL::ACC, ASL, ACC, 1, 2, N | Z | C, All, CPU::handle_ASL_ACC),
+    op(instructions::ASL::ZP, ASL, ZP, 2, 5, N | Z | C, All, CPU::handle_ASL_ZP),
+    op(instructions::ASL::ZPX, ASL, ZPX, 2, 6, N | Z | C, All, CPU::handle_ASL_ZPX),
+    op(instructions::ASL::ABS, ASL, ABS, 3, 6, N | Z | C, All, CPU::handle_ASL_ABS),
+    op(instructions::ASL::ABSX, ASL, ABSX, 3, 7, N | Z | C, All, CPU::handle_ASL_ABSX),
+    op(instructions::LSR::ACC, LSR, ACC, 1, 2, N | Z | C, All, CPU::handle_LSR_ACC),
+    op(instructions::LSR::ZP, LSR, ZP, 2, 5, N | Z | C, All, CPU::handle_LSR_ZP),
+    op(instructions::LSR::ZPX, LSR, ZPX, 2, 6, N | Z | C, All, CPU::handle_LSR_ZPX),
+    op(instructions::LSR::ABS, LSR, ABS, 3, 6, N | Z | C, All, CPU::handle_LSR_ABS),
+    op(instructions::LSR::ABSX, LSR, ABSX, 3, 7, N | Z | C, All, CPU::handle_LSR_ABSX),
+    op(instructions::ROL::ACC, ROL, ACC, 1, 2, N | Z | C, All, CPU::handle_ROL_ACC),
+    op(instructions::ROL::ZP, ROL, ZP, 2, 5, N | Z | C, All, CPU::handle_ROL_ZP),
+    op(instructions::ROL::ZPX, ROL, ZPX, 2, 6, N | Z | C, All, CPU::handle_ROL_ZPX),
+    op(instructions::ROL::ABS, ROL, ABS, 3, 6, N | Z | C, All, CPU::handle_ROL_ABS),
+    op(instructions::ROL::ABSX, ROL, ABSX, 3, 7, N | Z | C, All, CPU::handle_ROL_ABSX),
+    op(instructions::ROR::ACC, ROR, ACC, 1, 2, N | Z | C, All, CPU::handle_ROR_ACC),
+    op(instructions::ROR::ZP, ROR, ZP, 2, 5, N | Z | C, All, CPU::handle_ROR_ZP),
+    op(instructions::ROR::ZPX, ROR, ZPX, 2, 6, N | Z | C, All, CPU::handle_ROR_ZPX),
+    op(instructions::ROR::ABS, ROR, ABS, 3, 6, N | Z | C, All, CPU::handle_ROR_ABS),
+    op(instructions::ROR::ABSX, ROR, ABSX, 3, 7, N | Z | C, All, CPU::handle_ROR_ABSX),
+    op(instructions::CMP::IMM, CMP, IMM, 2, 2, N | Z | C, All, CPU::handle_CMP_IMM),
+    op(instructions::CMP::ZP, CMP, ZP, 2, 3, N | Z | C, All, CPU::handle_CMP_ZP),
+    op(instructions::CMP::ZPX, CMP, ZPX, 2, 4, N | Z | C, All, CPU::handle_CMP_ZPX),
+    op(instructions::CMP::ABS, CMP, ABS, 3, 4, N | Z | C, All, CPU::handle_CMP_ABS),
+    op(instructions::CMP::ABSX, CMP, ABSX, 3, 4, N | Z | C, All, CPU::handle_CMP_ABSX),
+    op(instructions::CMP::ABSY, CMP, ABSY, 3, 4, N | Z | C, All, CPU::handle_CMP_ABSY),
+    op(instructions::CMP::INDX, CMP, INDX, 2, 6, N | Z | C, All, CPU::handle_CMP_INDX),
+    op(instructions::CMP::INDY, CMP, INDY, 2, 5, N | Z | C, All, CPU::handle_CMP_INDY),
+    op(instructions::CPX::IMM, CPX, IMM, 2, 2, N | Z | C, All, CPU::handle_CPX_IMM),
+    op(instructions::CPX::ZP, CPX, ZP, 2, 3, N | Z | C, All, CPU::handle_CPX_ZP),
+    op(instructions::CPX::ABS, CPX, ABS, 3, 4, N | Z | C, All, CPU::handle_CPX_ABS),
+    op(instructions::CPY::IMM, CPY, IMM, 2, 2, N | Z | C, All, CPU::handle_CPY_IMM),
+    op(instructions::CPY::ZP, CPY, ZP, 2, 3, N | Z | C, All, CPU::handle_CPY_ZP),
+    op(instructions::CPY::ABS, CPY, ABS, 3, 4, N | Z | C, All, CPU::handle_CPY_ABS),
+    op(instructions::BCC::REL, BCC, REL, 2, 2, NONE, All, CPU::handle_BCC_REL),
+    op(instructions::BCS::REL, BCS, REL, 2, 2, NONE, All, CPU::handle_BCS_REL),
+    op(instructions::BEQ::REL, BEQ, REL, 2, 2, NONE, All, CPU::handle_BEQ_REL),
+    op(instructions::BNE::REL, BNE, REL, 2, 2, NONE, All, CPU::handle_BNE_REL),
+    op(instructions::BMI::REL, BMI, REL, 2, 2, NONE, All, CPU::handle_BMI_REL),
+    op(instructions::BPL::REL, BPL, REL, 2, 2, NONE, All, CPU::handle_BPL_REL),
+    op(instructions::BVC::REL, BVC, REL, 2, 2, NONE, All, CPU::handle_BVC_REL),
+    op(instructions::BVS::REL, BVS, REL, 2, 2, NONE, All, CPU::handle_BVS_REL),
+    op(instructions::PHA::IMP, PHA, IMP, 1, 3, NONE, All, CPU::handle_PHA_IMP),
+    op(instructions::PHP::IMP, PHP, IMP, 1, 3, NONE, All, CPU::handle_PHP_IMP),
+    op(instructions::PLA::IMP, PLA, IMP, 1, 4, N | Z, All, CPU::handle_PLA_IMP),
+    op(instructions::PLP::IMP, PLP, IMP, 1, 4, N | V | D | I | Z | C, All, CPU::handle_PLP_IMP),
+    op(instructions::JSR::ABS, JSR, ABS, 3, 6, NONE, All, CPU::handle_JSR_ABS),
+    op(instructions::RTS::IMP, RTS, IMP, 1, 6, NONE, All, CPU::handle_RTS_IMP),
+    op(instructions::RTI::IMP, RTI, IMP, 1, 6, N | V | D | I | Z | C, All, CPU::handle_RTI_IMP),
+    op(instructions::BRK::IMP, BRK, IMP, 2, 7, I, All, CPU::handle_BRK_IMP),
+    op(instructions::TAX::IMP, TAX, IMP, 1, 2, N | Z, All, CPU::handle_TAX_IMP),
+    op(instructions::TAY::IMP, TAY, IMP, 1, 2, N | Z, All, CPU::handle_TAY_IMP),
+    op(instructions::TXA::IMP, TXA, IMP, 1, 2, N | Z, All, CPU::handle_TXA_IMP),
+    op(instructions::TYA::IMP, TYA, IMP, 1, 2, N | Z, All, CPU::handle_TYA_IMP),
+    op(instructions::TSX::IMP, TSX, IMP, 1, 2, N | Z, All, CPU::handle_TSX_IMP),
+    op(instructions::TXS::IMP, TXS, IMP, 1, 2, NONE, All, CPU::handle_TXS_IMP),
+    op(instructions::DEX::IMP, DEX, IMP, 1, 2, N | Z, All, CPU::handle_DEX_IMP),
+    op(instructions::DEY::IMP, DEY, IMP, 1, 2, N | Z, All, CPU::handle_DEY_IMP),
+    op(instructions::INC::ZP, INC, ZP, 2, 5, N | Z, All, CPU::handle_INC_ZP),
+    op(instructions::INC::ZPX, INC, ZPX, 2, 6, N | Z, All, CPU::handle_INC_ZPX),
+    op(instructions::INC::ABS, INC, ABS, 3, 6, N | Z, All, CPU::handle_INC_ABS),
+    op(instructions::INC::ABSX, INC, ABSX, 3, 7, N | Z, All, CPU::handle_INC_ABSX),
+    op(instructions::DEC::ZP, DEC, ZP, 2, 5, N | Z, All, CPU::handle_DEC_ZP),
+    op(instructions::DEC::ZPX, DEC, ZPX, 2, 6, N | Z, All, CPU::handle_DEC_ZPX),
+    op(instructions::DEC::ABS, DEC, ABS, 3, 6, N | Z, All, CPU::handle_DEC_ABS),
+    op(instructions::DEC::ABSX, DEC, ABSX, 3, 7, N | Z, All, CPU::handle_DEC_ABSX),
+    op(instructions::CLC::IMP, CLC, IMP, 1, 2, C, All, CPU::handle_CLC_IMP),
+    op(instructions::SEC::IMP, SEC, IMP, 1, 2, C, All, CPU::handle_SEC_IMP),
+    op(instructions::CLI::IMP, CLI, IMP, 1, 2, I, All, CPU::handle_CLI_IMP),
+    op(instructions::SEI::IMP, SEI, IMP, 1, 2, I, All, CPU::handle_SEI_IMP),
+    op(instructions::CLV::IMP, CLV, IMP, 1, 2, V, All, CPU::handle_CLV_IMP),
+    op(instructions::CLD::IMP, CLD, IMP, 1, 2, D, All, CPU::handle_CLD_IMP),
+    op(instructions::SED::IMP, SED, IMP, 1, 2, D, All, CPU::handle_SED_IMP),
+    op(instructions::NOP::IMP, NOP, IMP, 1, 2, NONE, All, CPU::handle_NOP_IMP),
+];
+
+#[rustfmt::skip]
+const UNDOCUMENTED: &[OpcodeInfo] = &[
+    op(instructions::SBC::IMM_EB, SBC, IMM, 2, 2, N | V | Z | C, Nmos, CPU::handle_SBC_IMM),
+    op(instructions::NOP::IMP_1A, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMP_3A, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMP_5A, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMP_7A, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMP_DA, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMP_FA, NOP, IMP, 1, 2, NONE, Nmos, CPU::handle_NOP_IMP),
+    op(instructions::NOP::IMM_80, NOP, IMM, 2, 2, NONE, Nmos, CPU::handle_NOP_IMM),
+    op(instructions::NOP::IMM_82, NOP, IMM, 2, 2, NONE, Nmos, CPU::handle_NOP_IMM),
+    op(instructions::NOP::IMM_89, NOP, IMM, 2, 2, NONE, Nmos, CPU::handle_NOP_IMM),
+    op(instructions::NOP::IMM_C2, NOP, IMM, 2, 2, NONE, Nmos, CPU::handle_NOP_IMM),
+    op(instructions::NOP::IMM_E2, NOP, IMM, 2, 2, NONE, Nmos, CPU::handle_NOP_IMM),
+    op(instructions::NOP::ZP_04, NOP, ZP, 2, 3, NONE, Nmos, CPU::handle_NOP_ZP),
+    op(instructions::NOP::ZP_44, NOP, ZP, 2, 3, NONE, Nmos, CPU::handle_NOP_ZP),
+    op(instructions::NOP::ZP_64, NOP, ZP, 2, 3, NONE, Nmos, CPU::handle_NOP_ZP),
+    op(instructions::NOP::ZPX_14, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ZPX_34, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ZPX_54, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ZPX_74, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ZPX_D4, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ZPX_F4, NOP, ZPX, 2, 4, NONE, Nmos, CPU::handle_NOP_ZPX),
+    op(instructions::NOP::ABS_0C, NOP, ABS, 3, 4, NONE, Nmos, CPU::handle_NOP_ABS),
+    op(instructions::NOP::ABSX_1C, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::NOP::ABSX_3C, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::NOP::ABSX_5C, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::NOP::ABSX_7C, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::NOP::ABSX_DC, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::NOP::ABSX_FC, NOP, ABSX, 3, 4, NONE, Nmos, CPU::handle_NOP_ABSX),
+    op(instructions::LAX::ZP, LAX, ZP, 2, 3, N | Z, Nmos, CPU::handle_LAX_ZP),
+    op(instructions::LAX::ZPY, LAX, ZPY, 2, 4, N | Z, Nmos, CPU::handle_LAX_ZPY),
+    op(instructions::LAX::ABS, LAX, ABS, 3, 4, N | Z, Nmos, CPU::handle_LAX_ABS),
+    op(instructions::LAX::ABSY, LAX, ABSY, 3, 4, N | Z, Nmos, CPU::handle_LAX_ABSY),
+    op(instructions::LAX::INDX, LAX, INDX, 2, 6, N | Z, Nmos, CPU::handle_LAX_INDX),
+    op(instructions::LAX::INDY, LAX, INDY, 2, 5, N | Z, Nmos, CPU::handle_LAX_INDY),
+    op(instructions::SAX::ZP, SAX, ZP, 2, 3, NONE, Nmos, CPU::handle_SAX_ZP),
+    op(instructions::SAX::ZPY, SAX, ZPY, 2, 4, NONE, Nmos, CPU::handle_SAX_ZPY),
+    op(instructions::SAX::ABS, SAX, ABS, 3, 4, NONE, Nmos, CPU::handle_SAX_ABS),
+    op(instructions::SAX::INDX, SAX, INDX, 2, 6, NONE, Nmos, CPU::handle_SAX_INDX),
+    op(instructions::DCP::ZP, DCP, ZP, 2, 5, N | Z | C, Nmos, CPU::handle_DCP_ZP),
+    op(instructions::DCP::ZPX, DCP, ZPX, 2, 6, N | Z | C, Nmos, CPU::handle_DCP_ZPX),
+    op(instructions::DCP::ABS, DCP, ABS, 3, 6, N | Z | C, Nmos, CPU::handle_DCP_ABS),
+    op(instructions::DCP::ABSX, DCP, ABSX, 3, 7, N | Z | C, Nmos, CPU::handle_DCP_ABSX),
+    op(instructions::DCP::ABSY, DCP, ABSY, 3, 7, N | Z | C, Nmos, CPU::handle_DCP_ABSY),
+    op(instructions::DCP::INDX, DCP, INDX, 2, 8, N | Z | C, Nmos, CPU::handle_DCP_INDX),
+    op(instructions::DCP::INDY, DCP, INDY, 2, 8, N | Z | C, Nmos, CPU::handle_DCP_INDY),
+    op(instructions::ISC::ZP, ISC, ZP, 2, 5, N | V | Z | C, Nmos, CPU::handle_ISC_ZP),
+    op(instructions::ISC::ZPX, ISC, ZPX, 2, 6, N | V | Z | C, Nmos, CPU::handle_ISC_ZPX),
+    op(instructions::ISC::ABS, ISC, ABS, 3, 6, N | V | Z | C, Nmos, CPU::handle_ISC_ABS),
+    op(instructions::ISC::ABSX, ISC, ABSX, 3, 7, N | V | Z | C, Nmos, CPU::handle_ISC_ABSX),
+    op(instructions::ISC::ABSY, ISC, ABSY, 3, 7, N | V | Z | C, Nmos, CPU::handle_ISC_ABSY),
+    op(instructions::ISC::INDX, ISC, INDX, 2, 8, N | V | Z | C, Nmos, CPU::handle_ISC_INDX),
+    op(instructions::ISC::INDY, ISC, INDY, 2, 8, N | V | Z | C, Nmos, CPU::handle_ISC_INDY),
+    op(instructions::SLO::ZP, SLO, ZP, 2, 5, N | Z | C, Nmos, CPU::handle_SLO_ZP),
+    op(instructions::SLO::ZPX, SLO, ZPX, 2, 6, N | Z | C, Nmos, CPU::handle_SLO_ZPX),
+    op(instructions::SLO::ABS, SLO, ABS, 3, 6, N | Z | C, Nmos, CPU::handle_SLO_ABS),
+    op(instructions::SLO::ABSX, SLO, ABSX, 3, 7, N | Z | C, Nmos, CPU::handle_SLO_ABSX),
+    op(instructions::SLO::ABSY, SLO, ABSY, 3, 7, N | Z | C, Nmos, CPU::handle_SLO_ABSY),
+    op(instructions::SLO::INDX, SLO, INDX, 2, 8, N | Z | C, Nmos, CPU::handle_SLO_INDX),
+    op(instructions::SLO::INDY, SLO, INDY, 2, 8, N | Z | C, Nmos, CPU::handle_SLO_INDY),
+    op(instructions::RLA::ZP, RLA, ZP, 2, 5, N | Z | C, Nmos, CPU::handle_RLA_ZP),
+    op(instructions::RLA::ZPX, RLA, ZPX, 2, 6, N | Z | C, Nmos, CPU::handle_RLA_ZPX),
+    op(instructions::RLA::ABS, RLA, ABS, 3, 6, N | Z | C, Nmos, CPU::handle_RLA_ABS),
+    op(instructions::RLA::ABSX, RLA, ABSX, 3, 7, N | Z | C, Nmos, CPU::handle_RLA_ABSX),
+    op(instructions::RLA::ABSY, RLA, ABSY, 3, 7, N | Z | C, Nmos, CPU::handle_RLA_ABSY),
+    op(instructions::RLA::INDX, RLA, INDX, 2, 8, N | Z | C, Nmos, CPU::handle_RLA_INDX),
+    op(instructions::RLA::INDY, RLA, INDY, 2, 8, N | Z | C, Nmos, CPU::handle_RLA_INDY),
+    op(instructions::SRE::ZP, SRE, ZP, 2, 5, N | Z | C, Nmos, CPU::handle_SRE_ZP),
+    op(instructions::SRE::ZPX, SRE, ZPX, 2, 6, N | Z | C, Nmos, CPU::handle_SRE_ZPX),
+    op(instructions::SRE::ABS, SRE, ABS, 3, 6, N | Z | C, Nmos, CPU::handle_SRE_ABS),
+    op(instructions::SRE::ABSX, SRE, ABSX, 3, 7, N | Z | C, Nmos, CPU::handle_SRE_ABSX),
+    op(instructions::SRE::ABSY, SRE, ABSY, 3, 7, N | Z | C, Nmos, CPU::handle_SRE_ABSY),
+    op(instructions::SRE::INDX, SRE, INDX, 2, 8, N | Z | C, Nmos, CPU::handle_SRE_INDX),
+    op(instructions::SRE::INDY, SRE, INDY, 2, 8, N | Z | C, Nmos, CPU::handle_SRE_INDY),
+    op(instructions::RRA::ZP, RRA, ZP, 2, 5, N | V | Z | C, Nmos, CPU::handle_RRA_ZP),
+    op(instructions::RRA::ZPX, RRA, ZPX, 2, 6, N | V | Z | C, Nmos, CPU::handle_RRA_ZPX),
+    op(instructions::RRA::ABS, RRA, ABS, 3, 6, N | V | Z | C, Nmos, CPU::handle_RRA_ABS),
+    op(instructions::RRA::ABSX, RRA, ABSX, 3, 7, N | V | Z | C, Nmos, CPU::handle_RRA_ABSX),
+    op(instructions::RRA::ABSY, RRA, ABSY, 3, 7, N | V | Z | C, Nmos, CPU::handle_RRA_ABSY),
+    op(instructions::RRA::INDX, RRA, INDX, 2, 8, N | V | Z | C, Nmos, CPU::handle_RRA_INDX),
+    op(instructions::RRA::INDY, RRA, INDY, 2, 8, N | V | Z | C, Nmos, CPU::handle_RRA_INDY),
+    op(instructions::ANC::IMM, ANC, IMM, 2, 2, N | Z | C, Nmos, CPU::handle_ANC_IMM),
+    op(instructions::ANC::IMM_2B, ANC, IMM, 2, 2, N | Z | C, Nmos, CPU::handle_ANC_IMM),
+    op(instructions::ALR::IMM, ALR, IMM, 2, 2, N | Z | C, Nmos, CPU::handle_ALR_IMM),
+    op(instructions::ARR::IMM, ARR, IMM, 2, 2, N | V | Z | C, Nmos, CPU::handle_ARR_IMM),
+    op(instructions::SBX::IMM, SBX, IMM, 2, 2, N | Z | C, Nmos, CPU::handle_SBX_IMM),
+    op(instructions::SHA::ABSY, SHA, ABSY, 3, 5, NONE, Nmos, CPU::handle_SHA_ABSY),
+    op(instructions::SHA::INDY, SHA, INDY, 2, 6, NONE, Nmos, CPU::handle_SHA_INDY),
+    op(instructions::SHX::ABSY, SHX, ABSY, 3, 5, NONE, Nmos, CPU::handle_SHX_ABSY),
+    op(instructions::SHY::ABSX, SHY, ABSX, 3, 5, NONE, Nmos, CPU::handle_SHY_ABSX),
+    op(instructions::TAS::ABSY, TAS, ABSY, 3, 5, NONE, Nmos, CPU::handle_TAS_ABSY),
+    op(instructions::LAS::ABSY, LAS, ABSY, 3, 4, N | Z, Nmos, CPU::handle_LAS_ABSY),
+    op(instructions::ANE::IMM, ANE, IMM, 2, 2, N | Z, Nmos, CPU::handle_ANE_IMM),
+    op(instructions::LXA::IMM, LXA, IMM, 2, 2, N | Z, Nmos, CPU::handle_LXA_IMM),
+    op(instructions::JAM::IMP, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_12, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_22, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_32, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_42, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_52, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_62, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_72, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_92, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_B2, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_D2, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+    op(instructions::JAM::IMP_F2, JAM, IMP, 1, 0, NONE, Nmos, CPU::handle_JAM_IMP),
+];
+
+// Documented opcodes the 65C02 times differently or that clear D as well
+#[rustfmt::skip]
+const CMOS_CHANGES: &[OpcodeInfo] = &[
+    op(instructions::JMP::IND, JMP, IND, 3, 6, NONE, All, CPU::handle_JMP_IND),
+    op(instructions::ASL::ABSX, ASL, ABSX, 3, 6, N | Z | C, All, CPU::handle_ASL_ABSX),
+    op(instructions::LSR::ABSX, LSR, ABSX, 3, 6, N | Z | C, All, CPU::handle_LSR_ABSX),
+    op(instructions::ROL::ABSX, ROL, ABSX, 3, 6, N | Z | C, All, CPU::handle_ROL_ABSX),
+    op(instructions::ROR::ABSX, ROR, ABSX, 3, 6, N | Z | C, All, CPU::handle_ROR_ABSX),
+    op(instructions::BRK::IMP, BRK, IMP, 2, 7, D | I, All, CPU::handle_BRK_IMP),
+];
+
+#[rustfmt::skip]
+const CMOS_ADDITIONS: &[OpcodeInfo] = &[
+    op(instructions::LDA::IND, LDA, IND, 2, 5, N | Z, Cmos, CPU::handle_LDA_IND),
+    op(instructions::JMP::INDX, JMP, INDX, 3, 6, NONE, Cmos, CPU::handle_JMP_INDX),
+    op(instructions::STA::IND, STA, IND, 2, 5, NONE, Cmos, CPU::handle_STA_IND),
+    op(instructions::ADC::IND, ADC, IND, 2, 5, N | V | Z | C, Cmos, CPU::handle_ADC_IND),
+    op(instructions::SBC::IND, SBC, IND, 2, 5, N | V | Z | C, Cmos, CPU::handle_SBC_IND),
+    op(instructions::AND::IND, AND, IND, 2, 5, N | Z, Cmos, CPU::handle_AND_IND),
+    op(instructions::EOR::IND, EOR, IND, 2, 5, N | Z, Cmos, CPU::handle_EOR_IND),
+    op(instructions::ORA::IND, ORA, IND, 2, 5, N | Z, Cmos, CPU::handle_ORA_IND),
+    op(instructions::BIT::IMM, BIT, IMM, 2, 2, Z, Cmos, CPU::handle_BIT_IMM),
+    op(instructions::BIT::ZPX, BIT, ZPX, 2, 4, N | V | Z, Cmos, CPU::handle_BIT_ZPX),
+    op(instructions::BIT::ABSX, BIT, ABSX, 3, 4, N | V | Z, Cmos, CPU::handle_BIT_ABSX),
+    op(instructions::CMP::IND, CMP, IND, 2, 5, N | Z | C, Cmos, CPU::handle_CMP_IND),
+    op(instructions::INC::ACC, INC, ACC, 1, 2, N | Z, Cmos, CPU::handle_INC_ACC),
+    op(instructions::DEC::ACC, DEC, ACC, 1, 2, N | Z, Cmos, CPU::handle_DEC_ACC),
+    op(instructions::BRA::REL, BRA, REL, 2, 2, NONE, Cmos, CPU::handle_BRA_REL),
+    op(instructions::PHX::IMP, PHX, IMP, 1, 3, NONE, Cmos, CPU::handle_PHX_IMP),
+    op(instructions::PHY::IMP, PHY, IMP, 1, 3, NONE, Cmos, CPU::handle_PHY_IMP),
+    op(instructions::PLX::IMP, PLX, IMP, 1, 4, N | Z, Cmos, CPU::handle_PLX_IMP),
+    op(instructions::PLY::IMP, PLY, IMP, 1, 4, N | Z, Cmos, CPU::handle_PLY_IMP),
+    op(instructions::STZ::ZP, STZ, ZP, 2, 3, NONE, Cmos, CPU::handle_STZ_ZP),
+    op(instructions::STZ::ZPX, STZ, ZPX, 2, 4, NONE, Cmos, CPU::handle_STZ_ZPX),
+    op(instructions::STZ::ABS, STZ, ABS, 3, 4, NONE, Cmos, CPU::handle_STZ_ABS),
+    op(instructions::STZ::ABSX, STZ, ABSX, 3, 5, NONE, Cmos, CPU::handle_STZ_ABSX),
+    op(instructions::TRB::ZP, TRB, ZP, 2, 5, Z, Cmos, CPU::handle_TRB_ZP),
+    op(instructions::TRB::ABS, TRB, ABS, 3, 6, Z, Cmos, CPU::handle_TRB_ABS),
+    op(instructions::TSB::ZP, TSB, ZP, 2, 5, Z, Cmos, CPU::handle_TSB_ZP),
+    op(instructions::TSB::ABS, TSB, ABS, 3, 6, Z, Cmos, CPU::handle_TSB_ABS),
+    op(instructions::WAI::IMP, WAI, IMP, 1, 3, NONE, BitInstructions, CPU::handle_WAI_IMP),
+    op(instructions::STP::IMP, STP, IMP, 1, 3, NONE, BitInstructions, CPU::handle_STP_IMP),
+    op(instructions::RMB0::ZP, RMB0, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB1::ZP, RMB1, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB2::ZP, RMB2, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB3::ZP, RMB3, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB4::ZP, RMB4, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB5::ZP, RMB5, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB6::ZP, RMB6, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::RMB7::ZP, RMB7, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB0::ZP, SMB0, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB1::ZP, SMB1, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB2::ZP, SMB2, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB3::ZP, SMB3, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB4::ZP, SMB4, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB5::ZP, SMB5, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB6::ZP, SMB6, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::SMB7::ZP, SMB7, ZP, 2, 5, NONE, BitInstructions, CPU::handle_RMB_SMB_ZP),
+    op(instructions::BBR0::ZPREL, BBR0, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR1::ZPREL, BBR1, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR2::ZPREL, BBR2, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR3::ZPREL, BBR3, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR4::ZPREL, BBR4, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR5::ZPREL, BBR5, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR6::ZPREL, BBR6, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBR7::ZPREL, BBR7, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS0::ZPREL, BBS0, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS1::ZPREL, BBS1, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS2::ZPREL, BBS2, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS3::ZPREL, BBS3, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS4::ZPREL, BBS4, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS5::ZPREL, BBS5, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS6::ZPREL, BBS6, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+    op(instructions::BBS7::ZPREL, BBS7, ZPREL, 3, 5, NONE, BitInstructions, CPU::handle_BBR_BBS_ZPREL),
+
+    // Unassigned 65C02 opcodes are NOPs of fixed length
+    op(0x02, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0x03, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x0B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x13, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x1B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x22, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0x23, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x2B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x33, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x3B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x42, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0x43, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x44, NOP, ZP, 2, 3, NONE, Cmos, CPU::handle_NOP_ZP),
+    op(0x4B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x53, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x54, NOP, ZPX, 2, 4, NONE, Cmos, CPU::handle_NOP_ZPX),
+    op(0x5B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x5C, NOP, ABS, 3, 8, NONE, Cmos, CPU::handle_NOP_ABS),
+    op(0x62, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0x63, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x6B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x73, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x7B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x82, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0x83, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x8B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x93, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0x9B, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xA3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xAB, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xB3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xBB, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xC2, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0xC3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xD3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xD4, NOP, ZPX, 2, 4, NONE, Cmos, CPU::handle_NOP_ZPX),
+    op(0xDC, NOP, ABS, 3, 4, NONE, Cmos, CPU::handle_NOP_ABS),
+    op(0xE2, NOP, IMM, 2, 2, NONE, Cmos, CPU::handle_NOP_IMM),
+    op(0xE3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xEB, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xF3, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xF4, NOP, ZPX, 2, 4, NONE, Cmos, CPU::handle_NOP_ZPX),
+    op(0xFB, NOP, IMP, 1, 1, NONE, Cmos, CPU::handle_NOP_IMP),
+    op(0xFC, NOP, ABS, 3, 4, NONE, Cmos, CPU::handle_NOP_ABS),
+];
+
+static NMOS_OPCODES: [OpcodeInfo; 256] = table(DOCUMENTED, UNDOCUMENTED, &[]);
+const CMOS_TABLE: [OpcodeInfo; 256] = table(DOCUMENTED, CMOS_ADDITIONS, CMOS_CHANGES);
+
+static CMOS_OPCODES: [OpcodeInfo; 256] = CMOS_TABLE;
+static CMOS_OPCODES_WITHOUT_BIT_INSTRUCTIONS: [OpcodeInfo; 256] =
+    without_bit_instructions(CMOS_TABLE);
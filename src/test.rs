@@ -1,6 +1,11 @@
-use crate::cpu::CPU;
+use crate::cpu::{BusCycle, CpuError, CpuModel, StepInfo, CPU};
+use crate::cpu816::CPU816;
 use crate::instructions;
-use crate::memory::Memory;
+use crate::mapper::{Atari, Cartridge, Mapper, Mmc1, Nrom, UxRom};
+use crate::memory::{Bus, LongBus, LongMemory, Memory};
+use crate::memory_map::{MapError, MemoryMap, Region};
+use crate::opcodes::{self, flags, AddressingMode, Availability, Mnemonic};
+use crate::{Byte, DoubleWord, Word};
 
 #[allow(non_snake_case)]
 #[test]
@@ -12,11 +17,12 @@ fn LDA_ZPX_CAN_LOAD_AND_OFFSET() {
     mem.data[0xFFFC] = instructions::LDA::ZPX;
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
+    mem.data[0x0015] = 0x37;
     cpu.X = 0x5;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
-    assert_eq!(cpu.A, 0x16);
+    assert_eq!(cpu.A, 0x37);
 
     // Flags
     assert!(!cpu.Status.Zero);
@@ -28,6 +34,44 @@ fn LDA_ZPX_CAN_LOAD_AND_OFFSET() {
     assert!(!cpu.Status.Break);
 }
 
+#[allow(non_snake_case)]
+#[test]
+fn LDA_ZPX_WRAPS_IN_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::ZPX;
+    mem.data[0xFFFD] = 0x80;
+    mem.data[0x007F] = 0x42;
+    mem.data[0x017F] = 0x99;
+    cpu.X = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_INDX_WRAPS_POINTER_IN_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::INDX;
+    mem.data[0xFFFD] = 0xFE;
+    mem.data[0x00FF] = 0x00;
+    mem.data[0x0000] = 0x80;
+    mem.data[0x0100] = 0x90;
+    mem.data[0x8000] = 0x42;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
 #[allow(non_snake_case)]
 #[test]
 fn LDA_ZP_CAN_LOAD() {
@@ -39,7 +83,7 @@ fn LDA_ZP_CAN_LOAD() {
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x11);
 
@@ -63,7 +107,7 @@ fn LDA_IMM_CAN_LOAD() {
     mem.data[0xFFFC] = instructions::LDA::IMM;
     mem.data[0xFFFD] = 0x10;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x10);
 
@@ -87,7 +131,7 @@ fn LDA_FLAG_NEGATIVE() {
     mem.data[0xFFFC] = instructions::LDA::IMM;
     mem.data[0xFFFD] = 0x80;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x80);
 
@@ -111,7 +155,7 @@ fn LDA_FLAG_ZERO() {
     mem.data[0xFFFC] = instructions::LDA::IMM;
     mem.data[0xFFFD] = 0x00;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x00);
 
@@ -136,8 +180,10 @@ fn LDA_ABS_CAN_LOAD() {
     mem.data[0xFFFD] = 0x80;
     mem.data[0xFFFE] = 0x80;
     mem.data[0x8080] = 0x15;
+    // absolute addressing is not indexed
+    cpu.Y = 0x5;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x15);
 
@@ -164,7 +210,7 @@ fn LDA_ABSX_CAN_LOAD() {
     mem.data[0x8081] = 0x15;
     cpu.X = 0x01;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x15);
 
@@ -191,7 +237,7 @@ fn LDA_ABSY_CAN_LOAD() {
     mem.data[0x8183] = 0x0B;
     cpu.Y = 0x02;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x0B);
 
@@ -219,7 +265,7 @@ fn LDA_INDX_CAN_LOAD() {
     mem.data[0x1005] = 0x0F;
     cpu.X = 0x02;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x0F);
 
@@ -247,7 +293,7 @@ fn LDA_INDY_CAN_LOAD() {
     mem.data[0x4035] = 0x22;
     cpu.Y = 0x05;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x22);
 
@@ -275,7 +321,7 @@ fn LDA_INDY_CROSSED_PAGE() {
     mem.data[0x3101] = 0x80;
     cpu.Y = 0x02;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.A, 0x80);
 
@@ -300,11 +346,12 @@ fn LDX_ZPY_CAN_LOAD_AND_OFFSET() {
     mem.data[0xFFFC] = instructions::LDX::ZPY;
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
+    mem.data[0x0015] = 0x37;
     cpu.Y = 0x5;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
-    assert_eq!(cpu.X, 0x16);
+    assert_eq!(cpu.X, 0x37);
 
     // Flags
     assert!(!cpu.Status.Zero);
@@ -316,6 +363,23 @@ fn LDX_ZPY_CAN_LOAD_AND_OFFSET() {
     assert!(!cpu.Status.Break);
 }
 
+#[allow(non_snake_case)]
+#[test]
+fn LDX_ZPY_WRAPS_IN_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDX::ZPY;
+    mem.data[0xFFFD] = 0xF0;
+    mem.data[0x0010] = 0x42;
+    cpu.Y = 0x20;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x42);
+}
+
 #[allow(non_snake_case)]
 #[test]
 fn LDX_ZP_CAN_LOAD() {
@@ -327,7 +391,7 @@ fn LDX_ZP_CAN_LOAD() {
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.X, 0x11);
 
@@ -351,7 +415,7 @@ fn LDX_IMM_CAN_LOAD() {
     mem.data[0xFFFC] = instructions::LDX::IMM;
     mem.data[0xFFFD] = 0x10;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.X, 0x10);
 
@@ -376,11 +440,12 @@ fn LDY_ZPX_CAN_LOAD_AND_OFFSET() {
     mem.data[0xFFFC] = instructions::LDY::ZPX;
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
+    mem.data[0x0015] = 0x37;
     cpu.X = 0x5;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
-    assert_eq!(cpu.Y, 0x16);
+    assert_eq!(cpu.Y, 0x37);
 
     // Flags
     assert!(!cpu.Status.Zero);
@@ -403,7 +468,7 @@ fn LDY_ZP_CAN_LOAD() {
     mem.data[0xFFFD] = 0x10;
     mem.data[0x0010] = 0x11;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.Y, 0x11);
 
@@ -429,7 +494,7 @@ fn LDY_ABS_CAN_LOAD() {
     mem.data[0xFFFE] = 0x80;
     mem.data[0x8080] = 0x15;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.Y, 0x15);
 
@@ -456,7 +521,7 @@ fn JMP_ABS_CAN_JUMP() {
     mem.data[0xFFFD] = 0x80;
     mem.data[0xFFFE] = 0x80;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.PC, 0x8080);
 }
@@ -474,7 +539,7 @@ fn JMP_IND_CAN_JUMP() {
     mem.data[0x8080] = 0x90;
     mem.data[0x8081] = 0x80;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.PC, 0x8090);
 }
@@ -491,7 +556,7 @@ fn INX_CAN_INCREMENT() {
 
     mem.data[0xFFFC] = instructions::INX::IMP;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.X, 0x11);
 
@@ -517,7 +582,7 @@ fn INY_CAN_INCREMENT() {
 
     mem.data[0xFFFC] = instructions::INY::IMP;
 
-    cpu.execute(&mut mem);
+    cpu.execute(&mut mem).unwrap();
 
     assert_eq!(cpu.Y, 0x11);
 
@@ -530,3 +595,6243 @@ fn INY_CAN_INCREMENT() {
     assert!(!cpu.Status.InterruptDisable);
     assert!(!cpu.Status.Break);
 }
+
+// STA
+#[allow(non_snake_case)]
+#[test]
+fn STA_ZP_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ZP;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0x2F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x2F);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ZPX_CAN_STORE_AND_OFFSET() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0x2F;
+    cpu.X = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0015], 0x2F);
+    assert_eq!(mem.data[0x0010], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ZPX_WRAPS_AROUND_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ZPX;
+    mem.data[0xFFFD] = 0xFF;
+    cpu.A = 0x2F;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0001], 0x2F);
+    assert_eq!(mem.data[0x0101], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ABS_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ABS;
+    mem.data[0xFFFD] = 0x80;
+    mem.data[0xFFFE] = 0x40;
+    cpu.A = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x4080], 0x80);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ABSX_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ABSX;
+    mem.data[0xFFFD] = 0x80;
+    mem.data[0xFFFE] = 0x40;
+    cpu.A = 0x37;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x4081], 0x37);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ABSY_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ABSY;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x40;
+    cpu.A = 0x37;
+    cpu.Y = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x4101], 0x37);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_INDX_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::INDX;
+    mem.data[0xFFFD] = 0x1E;
+    mem.data[0x0020] = 0x05;
+    mem.data[0x0021] = 0x10;
+    cpu.A = 0x0F;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x1005], 0x0F);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_INDY_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x30;
+    mem.data[0x0021] = 0x40;
+    cpu.A = 0x22;
+    cpu.Y = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x4035], 0x22);
+}
+
+// STX
+#[allow(non_snake_case)]
+#[test]
+fn STX_ZP_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STX::ZP;
+    mem.data[0xFFFD] = 0x10;
+    cpu.X = 0x00;
+    mem.data[0x0010] = 0xAA;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x00);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STX_ZPY_CAN_STORE_AND_OFFSET() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STX::ZPY;
+    mem.data[0xFFFD] = 0xF0;
+    cpu.X = 0x42;
+    cpu.Y = 0x20;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STX_ABS_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STX::ABS;
+    mem.data[0xFFFD] = 0x34;
+    mem.data[0xFFFE] = 0x12;
+    cpu.X = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x1234], 0x42);
+}
+
+// STY
+#[allow(non_snake_case)]
+#[test]
+fn STY_ZP_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STY::ZP;
+    mem.data[0xFFFD] = 0x10;
+    cpu.Y = 0x99;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x99);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STY_ZPX_CAN_STORE_AND_OFFSET() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STY::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    cpu.Y = 0x99;
+    cpu.X = 0x03;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0013], 0x99);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STY_ABS_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STY::ABS;
+    mem.data[0xFFFD] = 0x34;
+    mem.data[0xFFFE] = 0x12;
+    cpu.Y = 0x99;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x1234], 0x99);
+}
+
+// ADC
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IMM_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x15);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IMM_ADDS_CARRY_IN() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0x05;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x16);
+    assert!(!cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IMM_SETS_CARRY_AND_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IMM_SETS_OVERFLOW_ON_POSITIVE_SUM() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x50;
+    cpu.A = 0x50;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xA0);
+
+    // Flags
+    assert!(cpu.Status.Overflow);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IMM_SETS_OVERFLOW_ON_NEGATIVE_SUM() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x90;
+    cpu.A = 0xD0;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x60);
+
+    // Flags
+    assert!(cpu.Status.Overflow);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_ZP_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x22;
+    cpu.A = 0x11;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_ZPX_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0x22;
+    cpu.A = 0x11;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_ABS_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x22;
+    cpu.A = 0x11;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_ABSX_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x22;
+    cpu.A = 0x11;
+    cpu.X = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_ABSY_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x22;
+    cpu.A = 0x11;
+    cpu.Y = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_INDX_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::INDX;
+    mem.data[0xFFFD] = 0x1E;
+    mem.data[0x0020] = 0x05;
+    mem.data[0x0021] = 0x10;
+    mem.data[0x1005] = 0x22;
+    cpu.A = 0x11;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_INDY_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x30;
+    mem.data[0x0021] = 0x40;
+    mem.data[0x4035] = 0x22;
+    cpu.A = 0x11;
+    cpu.Y = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x33);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_CAN_CHAIN_MULTI_BYTE_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+    cpu.PC = 0x0200;
+
+    // $0012/$0013 = $10FF + $0001
+    mem.data[0x0200] = instructions::LDA::IMM;
+    mem.data[0x0201] = 0xFF;
+    mem.data[0x0202] = instructions::ADC::IMM;
+    mem.data[0x0203] = 0x01;
+    mem.data[0x0204] = instructions::STA::ZP;
+    mem.data[0x0205] = 0x12;
+    mem.data[0x0206] = instructions::LDA::IMM;
+    mem.data[0x0207] = 0x10;
+    mem.data[0x0208] = instructions::ADC::IMM;
+    mem.data[0x0209] = 0x00;
+    mem.data[0x020A] = instructions::STA::ZP;
+    mem.data[0x020B] = 0x13;
+
+    for _ in 0..6 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(mem.data[0x0012], 0x00);
+    assert_eq!(mem.data[0x0013], 0x11);
+    assert!(!cpu.Status.Carry);
+}
+
+// SBC
+#[allow(non_snake_case)]
+#[test]
+fn SBC_IMM_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x05;
+    cpu.A = 0x10;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0B);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_IMM_SUBTRACTS_BORROW() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x05;
+    cpu.A = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0A);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_IMM_CLEARS_CARRY_ON_BORROW() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x02;
+    cpu.A = 0x01;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_IMM_SETS_OVERFLOW() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    // -128 - 1 does not fit in a signed byte
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x80;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x7F);
+
+    // Flags
+    assert!(cpu.Status.Overflow);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_ZP_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x11;
+    cpu.A = 0x33;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_ZPX_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0x11;
+    cpu.A = 0x33;
+    cpu.X = 0x02;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_ABS_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x11;
+    cpu.A = 0x33;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_ABSX_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x11;
+    cpu.A = 0x33;
+    cpu.X = 0x04;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_ABSY_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x11;
+    cpu.A = 0x33;
+    cpu.Y = 0x04;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_INDX_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::INDX;
+    mem.data[0xFFFD] = 0x1E;
+    mem.data[0x0020] = 0x05;
+    mem.data[0x0021] = 0x10;
+    mem.data[0x1005] = 0x11;
+    cpu.A = 0x33;
+    cpu.X = 0x02;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_INDY_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x30;
+    mem.data[0x0021] = 0x40;
+    mem.data[0x4035] = 0x11;
+    cpu.A = 0x33;
+    cpu.Y = 0x05;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x22);
+}
+
+// Decimal mode
+#[allow(non_snake_case)]
+#[test]
+fn ADC_DECIMAL_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x27;
+    cpu.A = 0x15;
+    cpu.Status.DecimalMode = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_DECIMAL_CARRIES_INTO_NEXT_BYTE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x46;
+    cpu.A = 0x58;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x05);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_DECIMAL_NMOS_FLAGS_FOLLOW_INTERMEDIATE_RESULT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Nmos6502);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x99;
+    cpu.Status.DecimalMode = true;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+    assert_eq!(step.Cycles, 2);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_DECIMAL_CMOS_FLAGS_FOLLOW_RESULT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x99;
+    cpu.Status.DecimalMode = true;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+    assert_eq!(step.Cycles, 3);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_DECIMAL_CAN_SUBTRACT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x15;
+    cpu.A = 0x42;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x27);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_DECIMAL_CAN_BORROW() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x21;
+    cpu.A = 0x12;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x91);
+    assert!(!cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_DECIMAL_NMOS_FLAGS_FOLLOW_BINARY_RESULT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Nmos6502);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x01;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+
+    cpu.PC = 0xFFFC;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // 00 - 01 = 99 with a borrow; N comes from the binary $FF
+    assert_eq!(cpu.A, 0x99);
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_DECIMAL_CMOS_TAKES_EXTRA_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x00;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x99);
+    assert_eq!(step.Cycles, 3);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+// AND
+#[allow(non_snake_case)]
+#[test]
+fn AND_IMM_CAN_MASK() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::AND::IMM;
+    mem.data[0xFFFD] = 0x0F;
+    cpu.A = 0x3C;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0C);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn AND_IMM_FLAG_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::AND::IMM;
+    mem.data[0xFFFD] = 0xF0;
+    cpu.A = 0x0F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn AND_ZP_CAN_MASK() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::AND::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xF0;
+    cpu.A = 0xBC;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xB0);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn AND_ABSX_CAN_MASK() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::AND::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2003] = 0x0F;
+    cpu.A = 0xBC;
+    cpu.X = 0x03;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0C);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn AND_INDY_CAN_MASK() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::AND::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x30;
+    mem.data[0x0021] = 0x40;
+    mem.data[0x4035] = 0x0F;
+    cpu.A = 0xBC;
+    cpu.Y = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0C);
+}
+
+// ORA
+#[allow(non_snake_case)]
+#[test]
+fn ORA_IMM_CAN_COMBINE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ORA::IMM;
+    mem.data[0xFFFD] = 0x80;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x81);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ORA_ZPX_CAN_COMBINE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ORA::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0x10;
+    cpu.A = 0x01;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x11);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ORA_ABSY_CAN_COMBINE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ORA::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2002] = 0x10;
+    cpu.A = 0x01;
+    cpu.Y = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x11);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ORA_INDX_CAN_COMBINE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ORA::INDX;
+    mem.data[0xFFFD] = 0x1E;
+    mem.data[0x0020] = 0x05;
+    mem.data[0x0021] = 0x10;
+    mem.data[0x1005] = 0x10;
+    cpu.A = 0x01;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x11);
+}
+
+// EOR
+#[allow(non_snake_case)]
+#[test]
+fn EOR_IMM_CAN_TOGGLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::EOR::IMM;
+    mem.data[0xFFFD] = 0xFF;
+    cpu.A = 0x0F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xF0);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn EOR_ABS_FLAG_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::EOR::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x5A;
+    cpu.A = 0x5A;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+// BIT
+#[allow(non_snake_case)]
+#[test]
+fn BIT_ZP_COPIES_BITS_INTO_FLAGS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::BIT::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xC0;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x01);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+    assert!(cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BIT_ABS_CAN_TEST() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::BIT::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x01;
+    cpu.A = 0x01;
+    cpu.Status.Overflow = true;
+    cpu.Status.Negative = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x01);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+// ASL
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ACC_CAN_SHIFT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ACC;
+    cpu.A = 0x41;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x82);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ACC_SHIFTS_INTO_CARRY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ACC;
+    cpu.A = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ZP_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x81;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x02);
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ZPX_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0x01;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0012], 0x02);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ABS_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0x02);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ASL_ABSX_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ASL::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x01;
+    cpu.X = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2004], 0x02);
+}
+
+// LSR
+#[allow(non_snake_case)]
+#[test]
+fn LSR_ACC_CAN_SHIFT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LSR::ACC;
+    cpu.A = 0x81;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x40);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LSR_ZP_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LSR::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LSR_ABSX_CAN_SHIFT_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LSR::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2004] = 0x04;
+    cpu.X = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2004], 0x02);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+}
+
+// ROL
+#[allow(non_snake_case)]
+#[test]
+fn ROL_ACC_ROTATES_CARRY_IN() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROL::ACC;
+    cpu.A = 0x80;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x01);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ROL_ZPX_CAN_ROTATE_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROL::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0011] = 0x40;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0011], 0x80);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ROL_ABS_CAN_ROTATE_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROL::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x01;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0x03);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+}
+
+// ROR
+#[allow(non_snake_case)]
+#[test]
+fn ROR_ACC_ROTATES_CARRY_IN() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROR::ACC;
+    cpu.A = 0x01;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x80);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ROR_ZP_CAN_ROTATE_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROR::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x01);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ROR_ABSX_CAN_ROTATE_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ROR::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0x01;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2001], 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+}
+
+// CMP
+#[allow(non_snake_case)]
+#[test]
+fn CMP_IMM_EQUAL() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::IMM;
+    mem.data[0xFFFD] = 0x42;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_IMM_GREATER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::IMM;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_IMM_LESS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::IMM;
+    mem.data[0xFFFD] = 0x43;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_IMM_IS_UNSIGNED() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.A = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_ZP_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x42;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_ZPX_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0011] = 0x42;
+    cpu.A = 0x42;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_ABS_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x42;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_ABSX_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0x42;
+    cpu.A = 0x42;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_ABSY_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0x42;
+    cpu.A = 0x42;
+    cpu.Y = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_INDX_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::INDX;
+    mem.data[0xFFFD] = 0x1E;
+    mem.data[0x0020] = 0x05;
+    mem.data[0x0021] = 0x10;
+    mem.data[0x1005] = 0x42;
+    cpu.A = 0x42;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMP_INDY_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CMP::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x30;
+    mem.data[0x0021] = 0x40;
+    mem.data[0x4035] = 0x42;
+    cpu.A = 0x42;
+    cpu.Y = 0x05;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+// CPX
+#[allow(non_snake_case)]
+#[test]
+fn CPX_IMM_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPX::IMM;
+    mem.data[0xFFFD] = 0x10;
+    cpu.X = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x10);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPX_ZP_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPX::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x20;
+    cpu.X = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPX_ABS_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPX::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x05;
+    cpu.X = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+// CPY
+#[allow(non_snake_case)]
+#[test]
+fn CPY_IMM_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPY::IMM;
+    mem.data[0xFFFD] = 0x10;
+    cpu.Y = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.Y, 0x10);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPY_ZP_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPY::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x20;
+    cpu.Y = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPY_ABS_CAN_COMPARE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CPY::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x05;
+    cpu.Y = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+// Branches
+#[allow(non_snake_case)]
+#[test]
+fn BEQ_CAN_BRANCH_FORWARD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BEQ::REL;
+    mem.data[0x0201] = 0x10;
+    cpu.Status.Zero = true;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0212);
+    assert_eq!(step.Cycles, 3);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BEQ_NOT_TAKEN() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BEQ::REL;
+    mem.data[0x0201] = 0x10;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(step.Cycles, 2);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BNE_CAN_BRANCH_BACKWARD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0210;
+    mem.data[0x0210] = instructions::BNE::REL;
+    mem.data[0x0211] = 0xF0;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(step.Cycles, 3);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BNE_CROSSING_PAGE_COSTS_EXTRA_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BNE::REL;
+    mem.data[0x0201] = 0x80;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0182);
+    assert_eq!(step.Cycles, 4);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BCC_CAN_BRANCH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x02F0;
+    mem.data[0x02F0] = instructions::BCC::REL;
+    mem.data[0x02F1] = 0x20;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0312);
+    assert_eq!(step.Cycles, 4);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BCS_CAN_BRANCH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BCS::REL;
+    mem.data[0x0201] = 0x01;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BMI_CAN_BRANCH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BMI::REL;
+    mem.data[0x0201] = 0x01;
+    cpu.Status.Negative = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BPL_NOT_TAKEN() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BPL::REL;
+    mem.data[0x0201] = 0x01;
+    cpu.Status.Negative = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0202);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BVC_CAN_BRANCH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BVC::REL;
+    mem.data[0x0201] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BVS_CAN_BRANCH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BVS::REL;
+    mem.data[0x0201] = 0x01;
+    cpu.Status.Overflow = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BNE_CAN_LOOP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDX::IMM;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = instructions::INX::IMP;
+    mem.data[0x0203] = instructions::CPX::IMM;
+    mem.data[0x0204] = 0x05;
+    mem.data[0x0205] = instructions::BNE::REL;
+    mem.data[0x0206] = 0xFB;
+
+    for _ in 0..16 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.X, 0x05);
+    assert_eq!(cpu.PC, 0x0207);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+}
+
+// Stack
+#[allow(non_snake_case)]
+#[test]
+fn PHA_CAN_PUSH() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PHA::IMP;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x01FF], 0x42);
+    assert_eq!(cpu.SP, 0xFE);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PLA_CAN_PULL() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PLA::IMP;
+    mem.data[0x01FF] = 0x80;
+    cpu.SP = 0xFE;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x80);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PHA_PLA_ROUND_TRIP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::PHA::IMP;
+    mem.data[0x0201] = instructions::LDA::IMM;
+    mem.data[0x0202] = 0x00;
+    mem.data[0x0203] = instructions::PLA::IMP;
+    cpu.A = 0x37;
+
+    for _ in 0..3 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.A, 0x37);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PHA_WRAPS_WITHIN_STACK_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PHA::IMP;
+    cpu.A = 0x42;
+    cpu.SP = 0x00;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0100], 0x42);
+    assert_eq!(cpu.SP, 0xFF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PLA_WRAPS_WITHIN_STACK_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PLA::IMP;
+    mem.data[0x0100] = 0x42;
+    cpu.SP = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.SP, 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PHP_PUSHES_STATUS_WITH_BREAK_AND_BIT_5() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PHP::IMP;
+    cpu.Status.Carry = true;
+    cpu.Status.Negative = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x01FF], 0b1011_0001);
+    assert_eq!(cpu.SP, 0xFE);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PLP_CAN_PULL_STATUS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PLP::IMP;
+    mem.data[0x01FF] = 0b1100_1111;
+    cpu.SP = 0xFE;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(cpu.Status.Overflow);
+    assert!(cpu.Status.DecimalMode);
+    assert!(cpu.Status.InterruptDisable);
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PLP_IGNORES_BREAK_AND_BIT_5() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::PLP::IMP;
+    mem.data[0x01FF] = 0b0011_0000;
+    cpu.SP = 0xFE;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+// Subroutines
+#[allow(non_snake_case)]
+#[test]
+fn JSR_PUSHES_RETURN_ADDRESS_MINUS_ONE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::JSR::ABS;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x30;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x3000);
+    assert_eq!(cpu.SP, 0xFD);
+    assert_eq!(mem.data[0x01FF], 0x02);
+    assert_eq!(mem.data[0x01FE], 0x02);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RTS_RETURNS_AFTER_JSR() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::JSR::ABS;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x30;
+    mem.data[0x0203] = instructions::LDX::IMM;
+    mem.data[0x0204] = 0x07;
+    mem.data[0x3000] = instructions::LDA::IMM;
+    mem.data[0x3001] = 0x42;
+    mem.data[0x3002] = instructions::RTS::IMP;
+
+    for _ in 0..4 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.X, 0x07);
+    assert_eq!(cpu.PC, 0x0205);
+    assert_eq!(cpu.SP, 0xFF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RTS_CAN_NEST() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::JSR::ABS;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x30;
+    mem.data[0x3000] = instructions::JSR::ABS;
+    mem.data[0x3001] = 0x00;
+    mem.data[0x3002] = 0x40;
+    mem.data[0x3003] = instructions::RTS::IMP;
+    mem.data[0x4000] = instructions::INX::IMP;
+    mem.data[0x4001] = instructions::RTS::IMP;
+
+    for _ in 0..5 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.X, 0x01);
+    assert_eq!(cpu.PC, 0x0203);
+    assert_eq!(cpu.SP, 0xFF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RTI_RESTORES_STATUS_AND_PC() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::RTI::IMP;
+    mem.data[0x01FD] = 0b1111_0011;
+    mem.data[0x01FE] = 0x34;
+    mem.data[0x01FF] = 0x12;
+    cpu.SP = 0xFC;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x1234);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(cpu.Status.Overflow);
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+// Interrupts
+#[allow(non_snake_case)]
+#[test]
+fn BRK_JUMPS_THROUGH_IRQ_VECTOR() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRK::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(cpu.SP, 0xFC);
+    assert_eq!(mem.data[0x01FF], 0x02);
+    assert_eq!(mem.data[0x01FE], 0x02);
+    assert_eq!(mem.data[0x01FD], 0b0011_0001);
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RTI_RETURNS_PAST_BRK_SIGNATURE_BYTE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRK::IMP;
+    mem.data[0x0201] = 0xEA;
+    mem.data[0x8000] = instructions::RTI::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+
+    for _ in 0..2 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.InterruptDisable);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn IRQ_IS_SERVICED_WHEN_ENABLED() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.set_irq(true);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(cpu.X, 0x00);
+    assert_eq!(mem.data[0x01FF], 0x02);
+    assert_eq!(mem.data[0x01FE], 0x00);
+    assert_eq!(mem.data[0x01FD], 0b0010_0000);
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn IRQ_IS_MASKED_BY_INTERRUPT_DISABLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.Status.InterruptDisable = true;
+    cpu.set_irq(true);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0201);
+    assert_eq!(cpu.X, 0x01);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn IRQ_IS_LEVEL_TRIGGERED() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x8000] = instructions::RTI::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.set_irq(true);
+
+    for _ in 0..3 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    // RTI restores I = 0 while the line is still held, so the IRQ fires again
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(cpu.SP, 0xFC);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NMI_IS_EDGE_TRIGGERED() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0x0201] = instructions::INX::IMP;
+    mem.data[0x9000] = instructions::RTI::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.Status.InterruptDisable = true;
+    cpu.set_nmi(true);
+
+    for _ in 0..4 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    // serviced once despite I being set, then not again while the line stays high
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(cpu.X, 0x02);
+    assert_eq!(cpu.SP, 0xFF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NMI_RETRIGGERS_AFTER_RELEASE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0x9000] = instructions::RTI::IMP;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x90;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.set_nmi(true);
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+    cpu.set_nmi(false);
+    cpu.set_nmi(true);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x9000);
+    assert_eq!(cpu.SP, 0xFC);
+}
+
+// Transfers
+#[allow(non_snake_case)]
+#[test]
+fn TAX_CAN_TRANSFER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TAX::IMP;
+    cpu.A = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x80);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TAY_CAN_TRANSFER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TAY::IMP;
+    cpu.A = 0x00;
+    cpu.Y = 0x12;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.Y, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TXA_CAN_TRANSFER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TXA::IMP;
+    cpu.X = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TYA_CAN_TRANSFER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TYA::IMP;
+    cpu.Y = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TSX_CAN_TRANSFER() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TSX::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TXS_DOES_NOT_AFFECT_FLAGS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TXS::IMP;
+    cpu.X = 0x00;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.SP, 0x00);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+// Increments and decrements
+#[allow(non_snake_case)]
+#[test]
+fn DEX_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEX::IMP;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEX_WRAPS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEX::IMP;
+    cpu.X = 0x00;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEY_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEY::IMP;
+    cpu.Y = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.Y, 0x0F);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INC_ZP_CAN_INCREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::INC::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x7F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x80);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INC_ZPX_CAN_INCREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::INC::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0xFF;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0012], 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INC_ABS_CAN_INCREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::INC::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0x02);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INC_ABSX_CAN_INCREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::INC::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2003] = 0x01;
+    cpu.X = 0x03;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2003], 0x02);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEC_ZP_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEC::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEC_ZPX_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEC::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0012] = 0x00;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0012], 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEC_ABS_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEC::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0x01);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEC_ABSX_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEC::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2003] = 0x02;
+    cpu.X = 0x03;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2003], 0x01);
+}
+
+// Flag instructions
+#[allow(non_snake_case)]
+#[test]
+fn CLC_CAN_CLEAR_CARRY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CLC::IMP;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SEC_CAN_SET_CARRY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SEC::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CLI_CAN_CLEAR_INTERRUPT_DISABLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CLI::IMP;
+    cpu.Status.InterruptDisable = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SEI_CAN_SET_INTERRUPT_DISABLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SEI::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CLV_CAN_CLEAR_OVERFLOW() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CLV::IMP;
+    cpu.Status.Overflow = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CLD_CAN_CLEAR_DECIMAL_MODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::CLD::IMP;
+    cpu.Status.DecimalMode = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SED_CAN_SET_DECIMAL_MODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SED::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.DecimalMode);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+// NOP
+#[allow(non_snake_case)]
+#[test]
+fn NOP_DOES_NOTHING() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::NOP::IMP;
+    cpu.A = 0x12;
+    cpu.X = 0x34;
+    cpu.Y = 0x56;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0xFFFD);
+    assert_eq!(cpu.A, 0x12);
+    assert_eq!(cpu.X, 0x34);
+    assert_eq!(cpu.Y, 0x56);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STARTUP_SEQUENCE_RUNS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::SEI::IMP;
+    mem.data[0x0201] = instructions::CLD::IMP;
+    mem.data[0x0202] = instructions::LDX::IMM;
+    mem.data[0x0203] = 0xFF;
+    mem.data[0x0204] = instructions::TXS::IMP;
+    cpu.Status.DecimalMode = true;
+
+    for _ in 0..4 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.SP, 0xFF);
+    assert_eq!(cpu.PC, 0x0205);
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(cpu.Status.Negative);
+}
+
+// Undocumented opcodes
+#[allow(non_snake_case)]
+#[test]
+fn LAX_ZP_LOADS_A_AND_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LAX::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x80);
+    assert_eq!(cpu.X, 0x80);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LAX_ABSY_LOADS_A_AND_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LAX::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2002] = 0x42;
+    cpu.Y = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.X, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SAX_ZP_STORES_A_AND_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SAX::ZP;
+    mem.data[0xFFFD] = 0x10;
+    cpu.A = 0xF0;
+    cpu.X = 0x3C;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x30);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DCP_ZP_DECREMENTS_AND_COMPARES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DCP::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x43;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x42);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ISC_ABS_INCREMENTS_AND_SUBTRACTS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ISC::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0x0F;
+    cpu.A = 0x30;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0x10);
+    assert_eq!(cpu.A, 0x20);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SLO_ZP_SHIFTS_AND_ORS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SLO::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0x81;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x02);
+    assert_eq!(cpu.A, 0x03);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RLA_ZPX_ROTATES_AND_ANDS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::RLA::ZPX;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0011] = 0x40;
+    cpu.A = 0xFF;
+    cpu.X = 0x01;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0011], 0x81);
+    assert_eq!(cpu.A, 0x81);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SRE_ABSX_SHIFTS_AND_EORS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SRE::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0x03;
+    cpu.A = 0x01;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2001], 0x01);
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RRA_INDY_ROTATES_AND_ADDS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::RRA::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x30;
+    mem.data[0x3001] = 0x03;
+    cpu.A = 0x10;
+    cpu.Y = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // ROR leaves $01 with C set, then ADC adds $01 + C
+    assert_eq!(mem.data[0x3001], 0x01);
+    assert_eq!(cpu.A, 0x12);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ANC_COPIES_NEGATIVE_INTO_CARRY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ANC::IMM_2B;
+    mem.data[0xFFFD] = 0xF0;
+    cpu.A = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x80);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ALR_ANDS_AND_SHIFTS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ALR::IMM;
+    mem.data[0xFFFD] = 0x03;
+    cpu.A = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x01);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ARR_ANDS_AND_ROTATES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ARR::IMM;
+    mem.data[0xFFFD] = 0xC0;
+    cpu.A = 0xFF;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xE0);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Overflow);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ARR_SETS_OVERFLOW_FROM_BITS_6_AND_5() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ARR::IMM;
+    mem.data[0xFFFD] = 0x40;
+    cpu.A = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x20);
+
+    // Flags
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.Overflow);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBX_SUBTRACTS_FROM_A_AND_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBX::IMM;
+    mem.data[0xFFFD] = 0x02;
+    cpu.A = 0x0F;
+    cpu.X = 0xFC;
+    cpu.Status.DecimalMode = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x0A);
+    assert_eq!(cpu.A, 0x0F);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_IMM_EB_MATCHES_SBC() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SBC::IMM_EB;
+    mem.data[0xFFFD] = 0x05;
+    cpu.A = 0x10;
+    cpu.Status.Carry = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0B);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NOP_VARIANTS_SKIP_OPERANDS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::NOP::IMP_1A;
+    mem.data[0x0201] = instructions::NOP::IMM_80;
+    mem.data[0x0202] = 0xFF;
+    mem.data[0x0203] = instructions::NOP::ZP_04;
+    mem.data[0x0204] = 0x10;
+    mem.data[0x0205] = instructions::NOP::ZPX_14;
+    mem.data[0x0206] = 0x10;
+    mem.data[0x0207] = instructions::NOP::ABS_0C;
+    mem.data[0x0208] = 0x00;
+    mem.data[0x0209] = 0x20;
+    mem.data[0x020A] = instructions::NOP::ABSX_1C;
+    mem.data[0x020B] = 0x00;
+    mem.data[0x020C] = 0x20;
+    cpu.A = 0x12;
+
+    for _ in 0..6 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x020D);
+    assert_eq!(cpu.A, 0x12);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    assert!(!cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SHX_STORES_X_AND_HIGH_BYTE_PLUS_ONE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SHX::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    cpu.X = 0xFF;
+    cpu.Y = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2001], 0x21);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SHY_PAGE_CROSS_CORRUPTS_HIGH_BYTE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SHY::ABSX;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x20;
+    cpu.Y = 0x05;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // $20FF + 2 crosses into $21xx, stored value $05 & $21 = $01 becomes the high byte
+    assert_eq!(mem.data[0x0101], 0x01);
+    assert_eq!(mem.data[0x2101], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SHA_INDY_STORES_A_AND_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SHA::INDY;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x30;
+    cpu.A = 0xFF;
+    cpu.X = 0xF7;
+    cpu.Y = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x3004], 0x31);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TAS_SETS_SP_AND_STORES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TAS::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    cpu.A = 0xF3;
+    cpu.X = 0x3F;
+    cpu.Y = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.SP, 0x33);
+    assert_eq!(mem.data[0x2001], 0x21);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LAS_ANDS_WITH_SP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LAS::ABSY;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0x8F;
+    cpu.Y = 0x01;
+    cpu.SP = 0xF0;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x80);
+    assert_eq!(cpu.X, 0x80);
+    assert_eq!(cpu.SP, 0x80);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ANE_USES_MAGIC_CONSTANT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ANE::IMM;
+    mem.data[0xFFFD] = 0xFF;
+    cpu.A = 0x00;
+    cpu.X = 0x3F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x2E);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LXA_USES_MAGIC_CONSTANT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LXA::IMM;
+    mem.data[0xFFFD] = 0x0F;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0F);
+    assert_eq!(cpu.X, 0x0F);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn UNDOCUMENTED_OPCODES_ARE_NMOS_ONLY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LXA::IMM;
+    mem.data[0xFFFD] = 0x0F;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // on the 65C02 $AB is a single byte NOP
+    assert_eq!(cpu.A, 0x01);
+    assert_eq!(cpu.X, 0x00);
+    assert_eq!(cpu.PC, 0xFFFD);
+}
+
+// 65C02
+#[allow(non_snake_case)]
+#[test]
+fn BRA_ALWAYS_BRANCHES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRA::REL;
+    mem.data[0x0201] = 0x10;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0212);
+    assert_eq!(step.Cycles, 3);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PHX_PLY_CAN_MOVE_X_TO_Y() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::PHX::IMP;
+    mem.data[0x0201] = instructions::PLY::IMP;
+    cpu.X = 0x80;
+
+    for _ in 0..2 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.Y, 0x80);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PHY_PLX_CAN_MOVE_Y_TO_X() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::PHY::IMP;
+    mem.data[0x0201] = instructions::PLX::IMP;
+    cpu.Y = 0x00;
+    cpu.X = 0x12;
+
+    for _ in 0..2 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.X, 0x00);
+    assert_eq!(cpu.SP, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STZ_ZP_CAN_STORE_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STZ::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STZ_ABSX_CAN_STORE_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STZ::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2002] = 0xFF;
+    cpu.X = 0x02;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2002], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TRB_CAN_RESET_BITS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TRB::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xFF;
+    cpu.A = 0x0F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0xF0);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TSB_CAN_SET_BITS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::TSB::ABS;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2000] = 0xF0;
+    cpu.A = 0x0F;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x2000], 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_IND_CAN_LOAD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::IND;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x30;
+    mem.data[0x3000] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_IND_WRAPS_POINTER_IN_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::IND;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0x00FF] = 0x00;
+    mem.data[0x0000] = 0x30;
+    mem.data[0x3000] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_IND_CAN_STORE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::IND;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x30;
+    cpu.A = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x3000], 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_IND_CAN_ADD() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IND;
+    mem.data[0xFFFD] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x30;
+    mem.data[0x3000] = 0x02;
+    cpu.A = 0x40;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INC_ACC_CAN_INCREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::INC::ACC;
+    cpu.A = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x00);
+
+    // Flags
+    assert!(cpu.Status.Zero);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DEC_ACC_CAN_DECREMENT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::DEC::ACC;
+    cpu.A = 0x00;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xFF);
+
+    // Flags
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BIT_IMM_ONLY_AFFECTS_ZERO() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::BIT::IMM;
+    mem.data[0xFFFD] = 0xC0;
+    cpu.A = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BIT_ABSX_COPIES_BITS_INTO_FLAGS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::BIT::ABSX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x20;
+    mem.data[0x2001] = 0xC0;
+    cpu.A = 0xFF;
+    cpu.X = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+    assert!(cpu.Status.Overflow);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn JMP_INDX_CAN_JUMP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::JMP::INDX;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x30;
+    mem.data[0x3004] = 0x34;
+    mem.data[0x3005] = 0x12;
+    cpu.X = 0x04;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x1234);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn JMP_IND_NMOS_PAGE_WRAP_BUG() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Nmos6502);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::JMP::IND;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x30;
+    mem.data[0x30FF] = 0x34;
+    mem.data[0x3000] = 0x12;
+    mem.data[0x3100] = 0x56;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x1234);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn JMP_IND_CMOS_CROSSES_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::JMP::IND;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x30;
+    mem.data[0x30FF] = 0x34;
+    mem.data[0x3000] = 0x12;
+    mem.data[0x3100] = 0x56;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x5634);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RMB_CAN_RESET_BIT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::RMB3::ZP;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xFF;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0xF7);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    // set by the CMOS reset
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SMB_CAN_SET_BIT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::SMB7::ZP;
+    mem.data[0xFFFD] = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0010], 0x80);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    // set by the CMOS reset
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BBR_BRANCHES_ON_CLEAR_BIT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BBR0::ZPREL;
+    mem.data[0x0201] = 0x10;
+    mem.data[0x0202] = 0x10;
+    mem.data[0x0010] = 0xFE;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0213);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BBR_FALLS_THROUGH_ON_SET_BIT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BBR0::ZPREL;
+    mem.data[0x0201] = 0x10;
+    mem.data[0x0202] = 0x10;
+    mem.data[0x0010] = 0x01;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BBS_BRANCHES_ON_SET_BIT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BBS6::ZPREL;
+    mem.data[0x0201] = 0x10;
+    mem.data[0x0202] = 0xFD;
+    mem.data[0x0010] = 0x40;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x0200);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_INTERRUPT_CLEARS_DECIMAL_MODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRK::IMP;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.Status.DecimalMode = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(mem.data[0x01FD], 0b0011_1100);
+
+    // Flags
+    assert!(!cpu.Status.DecimalMode);
+    assert!(cpu.Status.InterruptDisable);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NMOS_INTERRUPT_KEEPS_DECIMAL_MODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Nmos6502);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRK::IMP;
+    mem.data[0xFFFE] = 0x00;
+    mem.data[0xFFFF] = 0x80;
+    cpu.Status.DecimalMode = true;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x8000);
+
+    // Flags
+    assert!(cpu.Status.DecimalMode);
+    assert!(cpu.Status.InterruptDisable);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_UNDEFINED_OPCODES_ARE_NOPS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = 0x03;
+    mem.data[0x0201] = 0x02;
+    mem.data[0x0202] = 0xFF;
+    mem.data[0x0203] = 0x44;
+    mem.data[0x0204] = 0x10;
+    mem.data[0x0205] = 0xDC;
+    mem.data[0x0206] = 0x00;
+    mem.data[0x0207] = 0x20;
+    cpu.A = 0x12;
+
+    for _ in 0..4 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x0208);
+    assert_eq!(cpu.A, 0x12);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+    assert!(!cpu.Status.Overflow);
+    assert!(!cpu.Status.Carry);
+    assert!(!cpu.Status.DecimalMode);
+    // set by the CMOS reset
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.Break);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn WAI_WAITS_FOR_INTERRUPT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::WAI::IMP;
+    mem.data[0x0201] = instructions::INX::IMP;
+
+    for _ in 0..3 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x0201);
+    assert_eq!(cpu.X, 0x00);
+
+    cpu.Status.InterruptDisable = true;
+    cpu.set_irq(true);
+    cpu.execute(&mut mem).unwrap();
+
+    // a masked IRQ only resumes execution
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(cpu.X, 0x01);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STP_STOPS_UNTIL_RESET() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::STP::IMP;
+    mem.data[0x0201] = instructions::INX::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.set_nmi(true);
+    cpu.execute(&mut mem).unwrap();
+
+    // interrupts do not wake a stopped CPU
+    assert_eq!(cpu.PC, 0x0201);
+    assert_eq!(cpu.X, 0x00);
+
+    cpu.reset();
+    cpu.PC = 0x0201;
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x01);
+}
+
+// 65C816
+
+#[allow(non_snake_case)]
+#[test]
+fn XCE_SWITCHES_BETWEEN_EMULATION_AND_NATIVE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::CLC::IMP;
+    mem.data[0x0201] = instructions::XCE::IMP;
+    mem.data[0x0202] = instructions::REP::IMM;
+    mem.data[0x0203] = 0x30;
+    mem.data[0x0204] = instructions::SEC::IMP;
+    mem.data[0x0205] = instructions::XCE::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert!(!cpu.Emulation);
+    // the old E bit lands in carry
+    assert!(cpu.Status.Carry);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert!(!cpu.Status.MemorySelect);
+    assert!(!cpu.Status.IndexSelect);
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert!(cpu.Emulation);
+    assert!(!cpu.Status.Carry);
+    assert!(cpu.Status.MemorySelect);
+    assert!(cpu.Status.IndexSelect);
+    assert_eq!(cpu.SP, 0x01FF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn REP_CANNOT_WIDEN_REGISTERS_IN_EMULATION_MODE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::REP::IMM;
+    mem.data[0x0201] = 0x30;
+    mem.data[0x0202] = instructions::LDA::IMM;
+    mem.data[0x0203] = 0x34;
+    mem.data[0x0204] = 0x12;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0034);
+    assert_eq!(cpu.PC, 0x0204);
+    assert!(cpu.Status.MemorySelect);
+    assert!(cpu.Status.IndexSelect);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_LDX_IMM_LOAD_16_BITS_IN_NATIVE_MODE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::REP::IMM;
+    mem.data[0x0201] = 0x30;
+    mem.data[0x0202] = instructions::LDA::IMM;
+    mem.data[0x0203] = 0x34;
+    mem.data[0x0204] = 0x92;
+    mem.data[0x0205] = instructions::LDX::IMM;
+    mem.data[0x0206] = 0x78;
+    mem.data[0x0207] = 0x56;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x9234);
+    assert!(cpu.Status.Negative);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x5678);
+    assert_eq!(cpu.PC, 0x0208);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SEP_CLEARS_INDEX_HIGH_BYTES() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.IndexSelect = false;
+    cpu.X = 0x1234;
+    cpu.Y = 0xABCD;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::SEP::IMM;
+    mem.data[0x0201] = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x0034);
+    assert_eq!(cpu.Y, 0x00CD);
+    assert!(cpu.Status.IndexSelect);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ABSL_WRITES_TO_ANY_BANK() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.MemorySelect = false;
+    cpu.A = 0xBEEF;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::STA::ABSL;
+    mem.data[0x0201] = 0x56;
+    mem.data[0x0202] = 0x34;
+    mem.data[0x0203] = 0x12;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x12_3456], 0xEF);
+    assert_eq!(mem.data[0x12_3457], 0xBE);
+    assert_eq!(cpu.PC, 0x0204);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_ABS_READS_FROM_DATA_BANK() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.DBR = 0x7E;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::ABS;
+    mem.data[0x0201] = 0x10;
+    mem.data[0x0202] = 0x00;
+    mem.data[0x0010] = 0x11;
+    mem.data[0x7E_0010] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn DIRECT_PAGE_CAN_BE_RELOCATED() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.A = 0x1000;
+    cpu.DBR = 0x7E;
+    mem.data[0x0200] = instructions::TCD::IMP;
+    mem.data[0x0201] = instructions::LDA::ZP;
+    mem.data[0x0202] = 0x10;
+    mem.data[0x1010] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.D, 0x1000);
+    // direct page always lives in bank 0
+    assert_eq!(cpu.A & 0x00FF, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INDLY_READS_LONG_POINTER_FROM_DIRECT_PAGE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.Y = 0x05;
+    mem.data[0x0200] = instructions::LDA::INDLY;
+    mem.data[0x0201] = 0x20;
+    mem.data[0x0020] = 0x00;
+    mem.data[0x0021] = 0x80;
+    mem.data[0x0022] = 0x03;
+    mem.data[0x03_8005] = 0x99;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x99);
+    assert_eq!(cpu.PC, 0x0202);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INDL_POINTER_DOES_NOT_WRAP_IN_EMULATION_MODE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::INDL;
+    mem.data[0x0201] = 0xFF;
+    mem.data[0x00FF] = 0x34;
+    mem.data[0x0100] = 0x12;
+    mem.data[0x0101] = 0x05;
+    // where a page wrap would take the high byte
+    mem.data[0x0000] = 0x77;
+    mem.data[0x05_1234] = 0x99;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x99);
+    assert_eq!(cpu.PC, 0x0202);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MVN_COPIES_A_BLOCK_ONE_BYTE_PER_STEP() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.IndexSelect = false;
+    cpu.A = 0x0002;
+    cpu.X = 0x1000;
+    cpu.Y = 0x2000;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::MVN::BLK;
+    mem.data[0x0201] = 0x01;
+    mem.data[0x0202] = 0x02;
+    mem.data[0x02_1000] = 0xAA;
+    mem.data[0x02_1001] = 0xBB;
+    mem.data[0x02_1002] = 0xCC;
+
+    cpu.execute(&mut mem).unwrap();
+
+    // the move rewinds PC until the count runs out
+    assert_eq!(cpu.PC, 0x0200);
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x01_2000], 0xAA);
+    assert_eq!(mem.data[0x01_2001], 0xBB);
+    assert_eq!(mem.data[0x01_2002], 0xCC);
+    assert_eq!(cpu.A, 0xFFFF);
+    assert_eq!(cpu.X, 0x1003);
+    assert_eq!(cpu.Y, 0x2003);
+    assert_eq!(cpu.DBR, 0x01);
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MVP_COPIES_A_BLOCK_DOWNWARDS() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.A = 0x0001;
+    cpu.X = 0x11;
+    cpu.Y = 0x21;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::MVP::BLK;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x00;
+    mem.data[0x0010] = 0xAA;
+    mem.data[0x0011] = 0xBB;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0020], 0xAA);
+    assert_eq!(mem.data[0x0021], 0xBB);
+    assert_eq!(cpu.X, 0x0F);
+    assert_eq!(cpu.Y, 0x1F);
+    assert_eq!(cpu.PC, 0x0203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn JSL_RTL_CROSS_PROGRAM_BANKS() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::JSL::ABSL;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x80;
+    mem.data[0x0203] = 0x03;
+    mem.data[0x03_8000] = instructions::RTL::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PBR, 0x03);
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(cpu.SP, 0x01FC);
+    assert_eq!(mem.data[0x01FF], 0x00);
+    assert_eq!(mem.data[0x01FE], 0x02);
+    assert_eq!(mem.data[0x01FD], 0x03);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PBR, 0x00);
+    assert_eq!(cpu.PC, 0x0204);
+    assert_eq!(cpu.SP, 0x01FF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ADC_DECIMAL_ADDS_16_BITS() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.MemorySelect = false;
+    cpu.Status.DecimalMode = true;
+    cpu.A = 0x1999;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::ADC::IMM;
+    mem.data[0x0201] = 0x01;
+    mem.data[0x0202] = 0x00;
+    mem.data[0x0203] = instructions::ADC::IMM;
+    mem.data[0x0204] = 0x00;
+    mem.data[0x0205] = 0x80;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x2000);
+    assert!(!cpu.Status.Carry);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0000);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn SBC_DECIMAL_SUBTRACTS_16_BITS() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.MemorySelect = false;
+    cpu.Status.DecimalMode = true;
+    cpu.Status.Carry = true;
+    cpu.A = 0x1000;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::SBC::IMM;
+    mem.data[0x0201] = 0x01;
+    mem.data[0x0202] = 0x00;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0999);
+
+    // Flags
+    assert!(cpu.Status.Carry);
+    assert!(!cpu.Status.Zero);
+    assert!(!cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn EMULATION_STACK_WRAPS_IN_PAGE_ONE() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.SP = 0x0100;
+    cpu.A = 0x42;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::PHA::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0100], 0x42);
+    assert_eq!(cpu.SP, 0x01FF);
+
+    // the 65C816 instructions run past the page, SH is put back afterwards
+    cpu.SP = 0x0100;
+    cpu.D = 0x1234;
+    mem.data[0x0201] = instructions::PHD::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x0100], 0x12);
+    assert_eq!(mem.data[0x00FF], 0x34);
+    assert_eq!(cpu.SP, 0x01FE);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn XBA_SWAPS_ACCUMULATOR_BYTES() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.A = 0x8012;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::XBA::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x1280);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PEA_PLA_MOVE_16_BIT_VALUES() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.MemorySelect = false;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::PEA::ABS;
+    mem.data[0x0201] = 0xEF;
+    mem.data[0x0202] = 0xBE;
+    mem.data[0x0203] = instructions::PLA::IMP;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.SP, 0x01FD);
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0xBEEF);
+    assert_eq!(cpu.SP, 0x01FF);
+
+    // Flags
+    assert!(!cpu.Status.Zero);
+    assert!(cpu.Status.Negative);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn BRL_BRANCHES_WITH_16_BIT_OFFSET() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::BRL::REL;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x10;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x1203);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NATIVE_BRK_PUSHES_PROGRAM_BANK() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.DecimalMode = true;
+    cpu.PBR = 0x05;
+    cpu.PC = 0x8000;
+    mem.data[0x05_8000] = instructions::BRK::IMP;
+    mem.data[0xFFE6] = 0x00;
+    mem.data[0xFFE7] = 0x90;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PBR, 0x00);
+    assert_eq!(cpu.PC, 0x9000);
+    assert_eq!(cpu.SP, 0x01FB);
+    assert_eq!(mem.data[0x01FF], 0x05);
+    assert_eq!(mem.data[0x01FE], 0x80);
+    assert_eq!(mem.data[0x01FD], 0x02);
+    assert_eq!(mem.data[0x01FC], 0b0011_1100);
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.DecimalMode);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPU816_EXECUTE_RETURNS_STEP_INFO() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PBR = 0x01;
+    cpu.PC = 0x0200;
+    mem.data[0x01_0200] = instructions::NOP::IMP;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(step.PC, 0x0200);
+    assert_eq!(step.Opcode, Some(instructions::NOP::IMP));
+    assert_eq!(step.Cycles, 2);
+    assert_eq!(cpu.Cycles, 2);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPU816_WIDE_OPERANDS_AND_DIRECT_PAGE_ADD_CYCLES() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.Emulation = false;
+    cpu.Status.MemorySelect = false;
+    cpu.D = 0x0001;
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::IMM;
+    mem.data[0x0201] = 0x34;
+    mem.data[0x0202] = 0x12;
+    mem.data[0x0203] = instructions::LDA::ZP;
+    mem.data[0x0204] = 0x10;
+    mem.data[0x0205] = instructions::INC::ZP;
+    mem.data[0x0206] = 0x10;
+
+    // one cycle for the high byte
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 3);
+    // and one more for DL != 0
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 5);
+    // read-modify-write pays for the high byte twice
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 8);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPU816_INDEXED_READS_AND_BRANCHES_ADD_CYCLES() {
+    let mut mem = LongMemory::new();
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.X = 0x01;
+    mem.data[0x0200] = instructions::LDA::ABSX;
+    mem.data[0x0201] = 0xFF;
+    mem.data[0x0202] = 0x10;
+    mem.data[0x0203] = instructions::BRA::REL;
+    mem.data[0x0204] = 0x80;
+
+    // page crossed
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 5);
+    // taken, and across a page in emulation mode
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 4);
+    assert_eq!(cpu.PC, 0x0185);
+}
+
+// CPU models
+
+#[allow(non_snake_case)]
+#[test]
+fn WITH_MODEL_SETS_MATCHING_QUIRKS() {
+    let cpu = CPU::with_model(CpuModel::Ricoh2A03);
+
+    assert_eq!(cpu.model(), CpuModel::Ricoh2A03);
+    assert_eq!(*cpu.quirks(), CpuModel::Ricoh2A03.quirks());
+    assert!(!cpu.quirks().DecimalMode);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RICOH_2A03_IGNORES_DECIMAL_MODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Ricoh2A03);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::SED::IMP;
+    mem.data[0x0201] = instructions::ADC::IMM;
+    mem.data[0x0202] = 0x01;
+    cpu.A = 0x09;
+
+    cpu.execute(&mut mem).unwrap();
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x0A);
+
+    // Flags
+    assert!(cpu.Status.DecimalMode);
+    assert!(!cpu.Status.Carry);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RICOH_2A03_RESET_STATE() {
+    let mut cpu = CPU::with_model(CpuModel::Ricoh2A03);
+    cpu.reset();
+
+    assert_eq!(cpu.PC, 0xFFFC);
+    assert_eq!(cpu.SP, 0xFD);
+
+    // Flags
+    assert!(cpu.Status.InterruptDisable);
+    assert!(!cpu.Status.DecimalMode);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MOS_6507_MIRRORS_13_BIT_ADDRESS_SPACE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Mos6507);
+    cpu.reset();
+
+    // $FFFC is seen on the bus as $1FFC
+    mem.data[0x1FFC] = instructions::LDA::ABS;
+    mem.data[0x1FFD] = 0x80;
+    mem.data[0x1FFE] = 0xF0;
+    mem.data[0x1080] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.PC, 0xFFFF);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_65SC02_LACKS_BIT_INSTRUCTIONS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65SC02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::SMB7::ZP;
+    mem.data[0x0201] = instructions::STP::IMP;
+    mem.data[0x0202] = instructions::INX::IMP;
+
+    for _ in 0..3 {
+        cpu.execute(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x0203);
+    assert_eq!(cpu.X, 0x01);
+    assert_eq!(mem.data[0x00DB], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_65SC02_FIXES_JMP_IND_PAGE_WRAP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65SC02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::JMP::IND;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x30;
+    mem.data[0x30FF] = 0x80;
+    mem.data[0x3000] = 0x50;
+    mem.data[0x3100] = 0x40;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x4080);
+}
+
+// Address wraparound
+
+#[allow(non_snake_case)]
+#[test]
+fn FETCH_WRAPS_AT_END_OF_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0xFFFF;
+    mem.data[0xFFFF] = instructions::LDA::IMM;
+    mem.data[0x0000] = 0x42;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.PC, 0x0001);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn FETCH_WORD_WRAPS_AT_END_OF_MEMORY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0xFFFE;
+    mem.data[0xFFFE] = instructions::JMP::ABS;
+    mem.data[0xFFFF] = 0x34;
+    mem.data[0x0000] = 0x12;
+
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.PC, 0x1234);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_ABSX_WRAPS_ADDRESS_SPACE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::ABSX;
+    mem.data[0xFFFD] = 0xF0;
+    mem.data[0xFFFE] = 0xFF;
+    mem.data[0x0010] = 0x42;
+    cpu.X = 0x20;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(step.Cycles, 5);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_INDY_WRAPS_POINTER_IN_ZERO_PAGE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::INDY;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0x00FF] = 0x00;
+    mem.data[0x0000] = 0x80;
+    mem.data[0x0100] = 0x90;
+    mem.data[0x8004] = 0x42;
+    cpu.Y = 0x04;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(step.Cycles, 5);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_INDY_WRAPS_ADDRESS_SPACE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::LDA::INDY;
+    mem.data[0xFFFD] = 0x10;
+    mem.data[0x0010] = 0xFF;
+    mem.data[0x0011] = 0xFF;
+    mem.data[0x0001] = 0x42;
+    cpu.Y = 0x02;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(step.Cycles, 6);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn LDA_ABSY_REPORTS_PAGE_CROSSING() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::ABSY;
+    mem.data[0x0201] = 0x80;
+    mem.data[0x0202] = 0x30;
+    mem.data[0x0203] = instructions::LDA::ABSY;
+    mem.data[0x0204] = 0x01;
+    mem.data[0x0205] = 0x30;
+    cpu.Y = 0x80;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(step.Cycles, 5);
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(step.Cycles, 4);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn STA_ABSX_HAS_NO_PAGE_CROSSING_PENALTY() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::STA::ABSX;
+    mem.data[0xFFFD] = 0xFF;
+    mem.data[0xFFFE] = 0x30;
+    cpu.A = 0x42;
+    cpu.X = 0x01;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(mem.data[0x3100], 0x42);
+    assert_eq!(step.Cycles, 5);
+}
+
+// Cycle counting
+
+#[allow(non_snake_case)]
+#[test]
+fn EXECUTE_RETURNS_CYCLES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::IMM;
+    mem.data[0x0201] = 0x42;
+    mem.data[0x0202] = instructions::STA::ABSX;
+    mem.data[0x0203] = 0x00;
+    mem.data[0x0204] = 0x30;
+    mem.data[0x0205] = instructions::JSR::ABS;
+    mem.data[0x0206] = 0x00;
+    mem.data[0x0207] = 0x40;
+
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 2);
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 5);
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 6);
+    assert_eq!(cpu.Cycles, 13);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn PAGE_CROSSING_READ_TAKES_EXTRA_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::ABSX;
+    mem.data[0x0201] = 0xFF;
+    mem.data[0x0202] = 0x30;
+    cpu.X = 0x01;
+
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 5);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TAKEN_BRANCH_ACROSS_PAGE_TAKES_4_CYCLES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x02F0;
+    mem.data[0x02F0] = instructions::BNE::REL;
+    mem.data[0x02F1] = 0x20;
+    mem.data[0x0312] = instructions::BNE::REL;
+    mem.data[0x0313] = 0x02;
+    mem.data[0x0316] = instructions::BEQ::REL;
+    mem.data[0x0317] = 0x10;
+
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 4);
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 3);
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 2);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INTERRUPT_TAKES_7_CYCLES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.set_nmi(true);
+
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 7);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_CYCLES_DIFFER_FROM_NMOS() {
+    let mut mem = Memory::new();
+    let mut nmos = CPU::new();
+    let mut cmos = CPU::with_model(CpuModel::Cmos65C02);
+    nmos.reset();
+    cmos.reset();
+
+    mem.data[0xFFFC] = instructions::JMP::IND;
+    mem.data[0xFFFD] = 0x00;
+    mem.data[0xFFFE] = 0x30;
+
+    // the fixed page wrap costs the 65C02 a cycle
+    assert_eq!(nmos.execute(&mut mem).unwrap().Cycles, 5);
+    assert_eq!(cmos.execute(&mut mem).unwrap().Cycles, 6);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_DECIMAL_ADC_TAKES_EXTRA_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    mem.data[0xFFFC] = instructions::ADC::IMM;
+    mem.data[0xFFFD] = 0x01;
+    cpu.Status.DecimalMode = true;
+
+    assert_eq!(cpu.execute(&mut mem).unwrap().Cycles, 3);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RUN_CYCLES_RUNS_WHOLE_INSTRUCTIONS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    for address in 0x0200..0x0210 {
+        mem.data[address] = instructions::NOP::IMP;
+    }
+
+    assert_eq!(cpu.run_cycles(&mut mem, 5).unwrap(), 6);
+    assert_eq!(cpu.PC, 0x0203);
+    assert_eq!(cpu.Cycles, 6);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RUN_UNTIL_MEASURES_A_LOOP() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDX::IMM;
+    mem.data[0x0201] = 0x05;
+    mem.data[0x0202] = instructions::DEX::IMP;
+    mem.data[0x0203] = instructions::BNE::REL;
+    mem.data[0x0204] = 0xFD;
+
+    let cycles = cpu.run_until(&mut mem, |cpu, _| cpu.PC == 0x0205).unwrap();
+
+    assert_eq!(cycles, 26);
+    assert_eq!(cpu.X, 0x00);
+}
+
+// Cycle stepping
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_CHANGES_REGISTERS_ON_LAST_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::IMM;
+    mem.data[0x0201] = 0x42;
+
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0200, 0xA9));
+    assert_eq!(cpu.A, 0x00);
+    assert_eq!(cpu.PC, 0x0200);
+
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0201, 0x42));
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(cpu.PC, 0x0202);
+    assert_eq!(cpu.Cycles, 2);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_IMPLIED_READS_NEXT_BYTE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0x0201] = 0x99;
+
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0200, 0xE8));
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0201, 0x99));
+    assert_eq!(cpu.X, 0x01);
+    assert_eq!(cpu.PC, 0x0201);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_ONE_CYCLE_NOP_SKIPS_NEXT_BYTE_READ() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::with_model(CpuModel::Cmos65C02);
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = 0x03;
+    mem.data[0x0201] = instructions::INX::IMP;
+
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0200, 0x03));
+    assert_eq!(cpu.PC, 0x0201);
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Read(0x0201, 0xE8));
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_RMW_WRITES_TWICE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INC::ZP;
+    mem.data[0x0201] = 0x10;
+    mem.data[0x0010] = 0x41;
+
+    let cycles: Vec<BusCycle> = (0..5).map(|_| cpu.tick(&mut mem).unwrap()).collect();
+
+    assert_eq!(
+        cycles,
+        vec![
+            BusCycle::Read(0x0200, 0xE6),
+            BusCycle::Read(0x0201, 0x10),
+            BusCycle::Read(0x0010, 0x41),
+            BusCycle::Write(0x0010, 0x41),
+            BusCycle::Write(0x0010, 0x42),
+        ]
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_WRITE_HAPPENS_ON_ITS_CYCLE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.A = 0x42;
+    mem.data[0x0200] = instructions::STA::ABS;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x30;
+
+    for _ in 0..3 {
+        cpu.tick(&mut mem).unwrap();
+    }
+
+    assert_eq!(mem.data[0x3000], 0x00);
+
+    assert_eq!(cpu.tick(&mut mem).unwrap(), BusCycle::Write(0x3000, 0x42));
+    assert_eq!(mem.data[0x3000], 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_ABSX_READS_UNFIXED_ADDRESS() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.X = 0x10;
+    mem.data[0x0200] = instructions::LDA::ABSX;
+    mem.data[0x0201] = 0xF8;
+    mem.data[0x0202] = 0x30;
+    mem.data[0x0203] = instructions::STA::ABSX;
+    mem.data[0x0204] = 0x00;
+    mem.data[0x0205] = 0x30;
+    mem.data[0x3008] = 0x11;
+    mem.data[0x3108] = 0x22;
+
+    let load: Vec<BusCycle> = (0..5).map(|_| cpu.tick(&mut mem).unwrap()).collect();
+    let store: Vec<BusCycle> = (0..5).map(|_| cpu.tick(&mut mem).unwrap()).collect();
+
+    assert_eq!(load[3], BusCycle::Read(0x3008, 0x11));
+    assert_eq!(load[4], BusCycle::Read(0x3108, 0x22));
+    // stores read the target even without crossing a page
+    assert_eq!(store[3], BusCycle::Read(0x3010, 0x00));
+    assert_eq!(store[4], BusCycle::Write(0x3010, 0x22));
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_MATCHES_EXECUTE_FOR_EVERY_NMOS_OPCODE() {
+    // the JAM opcodes never finish
+    let jams = [
+        0x02, 0x12, 0x22, 0x32, 0x42, 0x52, 0x62, 0x72, 0x92, 0xB2, 0xD2, 0xF2,
+    ];
+
+    for opcode in 0..=0xFF {
+        if jams.contains(&opcode) {
+            continue;
+        }
+        for index in [0x01, 0xC0] {
+            let mut mem = Memory::new();
+            let mut cpu = CPU::new();
+            cpu.reset();
+
+            cpu.PC = 0x0200;
+            cpu.X = index;
+            cpu.Y = index;
+            cpu.A = 0x5A;
+            mem.data[0x0200] = opcode;
+            mem.data[0x0201] = 0x80;
+            mem.data[0x0202] = 0x30;
+            for address in 0x0080..0x0100 {
+                mem.data[address] = 0x40;
+            }
+            mem.data[0xFFFE] = 0x00;
+            mem.data[0xFFFF] = 0x04;
+
+            let mut expected_mem = mem.clone();
+            let mut expected = cpu.clone();
+            let cycles = expected.execute(&mut expected_mem).unwrap().Cycles;
+
+            for cycle in 0..cycles {
+                let bus = cpu.tick(&mut mem).unwrap();
+                assert_ne!(bus, BusCycle::Idle, "opcode {:02X} cycle {}", opcode, cycle);
+            }
+
+            assert_eq!(cpu.PC, expected.PC, "opcode {:02X}", opcode);
+            assert_eq!(cpu.A, expected.A, "opcode {:02X}", opcode);
+            assert_eq!(cpu.X, expected.X, "opcode {:02X}", opcode);
+            assert_eq!(cpu.Y, expected.Y, "opcode {:02X}", opcode);
+            assert_eq!(cpu.SP, expected.SP, "opcode {:02X}", opcode);
+            assert_eq!(cpu.Cycles, expected.Cycles, "opcode {:02X}", opcode);
+            assert!(mem.data == expected_mem.data, "opcode {:02X}", opcode);
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_TAKES_NMI_AFTER_INSTRUCTION_IN_FLIGHT() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::IMM;
+    mem.data[0x0201] = 0x42;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x80;
+
+    cpu.tick(&mut mem).unwrap();
+    cpu.set_nmi(true);
+    cpu.tick(&mut mem).unwrap();
+
+    assert_eq!(cpu.A, 0x42);
+
+    for _ in 0..7 {
+        cpu.tick(&mut mem).unwrap();
+    }
+
+    assert_eq!(cpu.PC, 0x8000);
+    assert_eq!(mem.data[0x01FF], 0x02);
+    assert_eq!(mem.data[0x01FE], 0x02);
+}
+
+// Step results
+
+#[allow(non_snake_case)]
+#[test]
+fn EXECUTE_REPORTS_OPCODE_PC_AND_CYCLES() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::LDA::ABS;
+    mem.data[0x0201] = 0x00;
+    mem.data[0x0202] = 0x30;
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(
+        step,
+        StepInfo {
+            PC: 0x0200,
+            Opcode: Some(instructions::LDA::ABS),
+            Cycles: 4,
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn INTERRUPT_STEP_HAS_NO_OPCODE() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0xFFFA] = 0x00;
+    mem.data[0xFFFB] = 0x80;
+    cpu.set_nmi(true);
+
+    let step = cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(step.PC, 0x0200);
+    assert_eq!(step.Opcode, None);
+    assert_eq!(step.Cycles, 7);
+    assert_eq!(cpu.PC, 0x8000);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn JAM_HALTS_UNTIL_RESET() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::JAM::IMP;
+    mem.data[0x0201] = instructions::INX::IMP;
+
+    let expected = CpuError::Jammed {
+        Opcode: instructions::JAM::IMP,
+        PC: 0x0200,
+    };
+    assert_eq!(cpu.execute(&mut mem), Err(expected));
+    assert_eq!(cpu.execute(&mut mem), Err(expected));
+    assert_eq!(cpu.X, 0x00);
+
+    cpu.reset();
+    cpu.PC = 0x0201;
+    cpu.execute(&mut mem).unwrap();
+
+    assert_eq!(cpu.X, 0x01);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RUN_CYCLES_AND_TICK_REPORT_JAM() {
+    let mut mem = Memory::new();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    mem.data[0x0200] = instructions::INX::IMP;
+    mem.data[0x0201] = instructions::JAM::IMP_F2;
+
+    let expected = CpuError::Jammed {
+        Opcode: instructions::JAM::IMP_F2,
+        PC: 0x0201,
+    };
+    assert_eq!(cpu.run_cycles(&mut mem, 100), Err(expected));
+    assert_eq!(cpu.tick(&mut mem), Err(expected));
+    assert_eq!(cpu.X, 0x01);
+}
+
+// Opcode table
+
+#[allow(non_snake_case)]
+#[test]
+fn OPCODE_INFO_DESCRIBES_LDA_IMM() {
+    let info = opcodes::opcode_info(instructions::LDA::IMM);
+
+    assert_eq!(info.Opcode, 0xA9);
+    assert_eq!(info.Mnemonic, Mnemonic::LDA);
+    assert_eq!(info.Mode, AddressingMode::IMM);
+    assert_eq!(info.Bytes, 2);
+    assert_eq!(info.Cycles, 2);
+    assert_eq!(info.Flags, flags::N | flags::Z);
+    assert_eq!(info.Availability, Availability::All);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn OPCODE_INFO_DEPENDS_ON_MODEL() {
+    let nmos = opcodes::opcode_info_for(CpuModel::Nmos6502, 0x07);
+    let cmos = opcodes::opcode_info_for(CpuModel::Cmos65C02, 0x07);
+    let sc02 = opcodes::opcode_info_for(CpuModel::Cmos65SC02, 0x07);
+
+    assert_eq!(nmos.Mnemonic, Mnemonic::SLO);
+    assert_eq!(cmos.Mnemonic, Mnemonic::RMB0);
+    assert!(cmos.Availability.includes(CpuModel::Cmos65C02));
+    assert!(!cmos.Availability.includes(CpuModel::Cmos65SC02));
+    assert_eq!(sc02.Mnemonic, Mnemonic::NOP);
+    assert_eq!(sc02.Bytes, 1);
+    assert_eq!(sc02.Cycles, 1);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CMOS_TABLE_SHARES_DOCUMENTED_OPCODES() {
+    // only timings and BRK clearing D differ
+    let changed = [
+        instructions::JMP::IND,
+        instructions::ASL::ABSX,
+        instructions::LSR::ABSX,
+        instructions::ROL::ABSX,
+        instructions::ROR::ABSX,
+        instructions::BRK::IMP,
+    ];
+
+    for opcode in 0..=0xFF {
+        let nmos = opcodes::opcode_info_for(CpuModel::Nmos6502, opcode);
+        let cmos = opcodes::opcode_info_for(CpuModel::Cmos65C02, opcode);
+        if nmos.Availability != Availability::All {
+            continue;
+        }
+
+        assert_eq!(cmos.Mnemonic, nmos.Mnemonic, "opcode {:02X}", opcode);
+        assert_eq!(cmos.Mode, nmos.Mode, "opcode {:02X}", opcode);
+        assert_eq!(
+            changed.contains(&opcode),
+            cmos != nmos,
+            "opcode {:02X}",
+            opcode
+        );
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn OPCODE_TABLE_MATCHES_EXECUTION() {
+    // everything that does not simply fall through to the next instruction
+    let control_flow = [
+        Mnemonic::JMP,
+        Mnemonic::JSR,
+        Mnemonic::RTS,
+        Mnemonic::RTI,
+        Mnemonic::BRK,
+        Mnemonic::JAM,
+    ];
+
+    for model in [
+        CpuModel::Nmos6502,
+        CpuModel::Cmos65C02,
+        CpuModel::Cmos65SC02,
+    ] {
+        for opcode in 0..=0xFF {
+            let info = opcodes::opcode_info_for(model, opcode);
+            assert_eq!(info.Opcode, opcode);
+            if control_flow.contains(&info.Mnemonic)
+                || info.Mode == AddressingMode::REL
+                || info.Mode == AddressingMode::ZPREL
+            {
+                continue;
+            }
+
+            let mut mem = Memory::new();
+            let mut cpu = CPU::with_model(model);
+            cpu.reset();
+
+            cpu.PC = 0x0200;
+            mem.data[0x0200] = opcode;
+            mem.data[0x0201] = 0x80;
+            mem.data[0x0202] = 0x30;
+            let step = cpu.execute(&mut mem).unwrap();
+
+            assert_eq!(
+                cpu.PC,
+                0x0200 + info.Bytes as u16,
+                "{:?} opcode {:02X}",
+                model,
+                opcode
+            );
+            assert_eq!(
+                step.Cycles, info.Cycles,
+                "{:?} opcode {:02X}",
+                model, opcode
+            );
+        }
+    }
+}
+
+// Bus
+
+// A latch at $D000 in front of plain memory; reading it hands out the
+// last value written and clears it
+struct Latch {
+    mem: Memory,
+    value: Byte,
+}
+
+impl Bus for Latch {
+    fn read(&mut self, address: Word) -> Byte {
+        match address {
+            0xD000 => std::mem::take(&mut self.value),
+            _ => self.mem.read(address),
+        }
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        match address {
+            0xD000 => self.value = value,
+            _ => self.mem.write(address, value),
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPU_RUNS_ON_ANY_BUS() {
+    let mut bus = Latch {
+        mem: Memory::new(),
+        value: 0,
+    };
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.A = 0x42;
+    bus.mem.data[0x0200] = instructions::STA::ABS;
+    bus.mem.data[0x0201] = 0x00;
+    bus.mem.data[0x0202] = 0xD0;
+    bus.mem.data[0x0203] = instructions::LDX::ABS;
+    bus.mem.data[0x0204] = 0x00;
+    bus.mem.data[0x0205] = 0xD0;
+    bus.mem.data[0x0206] = instructions::LDY::ABS;
+    bus.mem.data[0x0207] = 0x00;
+    bus.mem.data[0x0208] = 0xD0;
+    cpu.execute(&mut bus).unwrap();
+    cpu.execute(&mut bus).unwrap();
+    cpu.execute(&mut bus).unwrap();
+
+    assert_eq!(cpu.X, 0x42);
+    assert_eq!(cpu.Y, 0x00);
+    assert_eq!(bus.mem.data[0xD000], 0x00);
+}
+
+// The same latch for the 65C816, at $01D000
+struct LongLatch {
+    mem: LongMemory,
+    value: Byte,
+}
+
+impl LongBus for LongLatch {
+    fn read(&mut self, address: DoubleWord) -> Byte {
+        match address {
+            0x01_D000 => std::mem::take(&mut self.value),
+            _ => self.mem.read(address),
+        }
+    }
+
+    fn write(&mut self, address: DoubleWord, value: Byte) {
+        match address {
+            0x01_D000 => self.value = value,
+            _ => self.mem.write(address, value),
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn CPU816_RUNS_ON_ANY_LONG_BUS() {
+    let mut bus = LongLatch {
+        mem: LongMemory::new(),
+        value: 0,
+    };
+    let mut cpu = CPU816::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    cpu.A = 0x42;
+    bus.mem.data[0x0200] = instructions::STA::ABSL;
+    bus.mem.data[0x0201] = 0x00;
+    bus.mem.data[0x0202] = 0xD0;
+    bus.mem.data[0x0203] = 0x01;
+    bus.mem.data[0x0204] = instructions::LDX::ABS;
+    bus.mem.data[0x0205] = 0x00;
+    bus.mem.data[0x0206] = 0xD0;
+    bus.mem.data[0x0207] = instructions::LDA::IMM;
+    bus.mem.data[0x0208] = 0x00;
+    bus.mem.data[0x0209] = instructions::LDA::ABSL;
+    bus.mem.data[0x020A] = 0x00;
+    bus.mem.data[0x020B] = 0xD0;
+    bus.mem.data[0x020C] = 0x01;
+    for _ in 0..4 {
+        cpu.execute(&mut bus).unwrap();
+    }
+
+    // bank 0 is plain memory
+    assert_eq!(cpu.X, 0x00);
+    assert_eq!(cpu.A, 0x42);
+    assert_eq!(bus.value, 0x00);
+    assert_eq!(bus.mem.data[0x01_D000], 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn TICK_READS_EACH_BUS_ADDRESS_ONCE() {
+    let mut bus = Latch {
+        mem: Memory::new(),
+        value: 0x42,
+    };
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x0200;
+    bus.mem.data[0x0200] = instructions::LDA::ABS;
+    bus.mem.data[0x0201] = 0x00;
+    bus.mem.data[0x0202] = 0xD0;
+
+    // replaying the earlier cycles must not read the latch again
+    for _ in 0..4 {
+        cpu.tick(&mut bus).unwrap();
+    }
+
+    assert_eq!(cpu.A, 0x42);
+}
+
+// Memory map
+
+// Eight registers, mapped like the NES PPU
+struct Registers([Byte; 8]);
+
+impl Bus for Registers {
+    fn read(&mut self, address: Word) -> Byte {
+        self.0[address as usize]
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.0[address as usize] = value;
+    }
+}
+
+fn nes_map(rom: Vec<Byte>) -> MemoryMap {
+    MemoryMap::new(vec![
+        (0x0000..=0x07FF, Region::Ram),
+        (0x0800..=0x1FFF, Region::Mirror(0x0000)),
+        (0x2000..=0x2007, Region::Io(Box::new(Registers([0; 8])))),
+        (0x2008..=0x3FFF, Region::Mirror(0x2000)),
+        (0x4000..=0x7FFF, Region::OpenBus),
+        (0x8000..=0xFFFF, Region::Rom(rom)),
+    ])
+    .unwrap()
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_MIRRORS_RAM() {
+    let mut map = nes_map(vec![0; 0x8000]);
+
+    map.write(0x0801, 0x42);
+    map.write(0x1FFF, 0x24);
+
+    assert_eq!(map.read(0x0001), 0x42);
+    assert_eq!(map.read(0x1001), 0x42);
+    assert_eq!(map.read(0x1801), 0x42);
+    assert_eq!(map.read(0x07FF), 0x24);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_ROUTES_IO_THROUGH_MIRRORS() {
+    let mut map = nes_map(vec![0; 0x8000]);
+
+    map.write(0x3FF9, 0x55);
+
+    assert_eq!(map.read(0x2001), 0x55);
+    assert_eq!(map.read(0x2009), 0x55);
+    assert_eq!(map.read(0x2000), 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_ROM_IGNORES_WRITES() {
+    let mut rom = vec![0; 0x8000];
+    rom[0x0000] = instructions::LDA::IMM;
+    rom[0x0001] = 0x42;
+    rom[0x0002] = instructions::STA::ABS;
+    rom[0x0003] = 0x00;
+    rom[0x0004] = 0x80;
+    rom[0x0005] = instructions::STA::ABS;
+    rom[0x0006] = 0x01;
+    rom[0x0007] = 0x08;
+    let mut map = nes_map(rom);
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    cpu.PC = 0x8000;
+    cpu.execute(&mut map).unwrap();
+    cpu.execute(&mut map).unwrap();
+    cpu.execute(&mut map).unwrap();
+
+    assert_eq!(map.read(0x8000), instructions::LDA::IMM);
+    assert_eq!(map.read(0x0001), 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_OPEN_BUS_READS_LAST_VALUE() {
+    let mut map = nes_map(vec![0; 0x8000]);
+
+    map.write(0x0010, 0x99);
+    map.read(0x0010);
+
+    assert_eq!(map.read(0x5000), 0x99);
+    map.write(0x4000, 0x11);
+    assert_eq!(map.read(0x6000), 0x11);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_REPORTS_OVERLAPS() {
+    let map = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::Ram),
+        (0x7000..=0xFFFF, Region::Ram),
+    ]);
+
+    assert_eq!(
+        map.err(),
+        Some(MapError::Overlap {
+            Start: 0x7000,
+            End: 0xFFFF,
+            With: 0x0000,
+        })
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_REPORTS_UNMAPPED_RANGES() {
+    let gap = MemoryMap::new(vec![
+        (0x0000..=0x07FF, Region::Ram),
+        (0x8000..=0xFFFF, Region::Ram),
+    ]);
+    let end = MemoryMap::new(vec![(0x0000..=0xBFFF, Region::Ram)]);
+
+    assert_eq!(
+        gap.err(),
+        Some(MapError::Unmapped {
+            Start: 0x0800,
+            End: 0x7FFF,
+        })
+    );
+    assert_eq!(
+        end.err(),
+        Some(MapError::Unmapped {
+            Start: 0xC000,
+            End: 0xFFFF,
+        })
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_REPORTS_BAD_REGIONS() {
+    let rom = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::Ram),
+        (0x8000..=0xFFFF, Region::Rom(vec![0; 0x4000])),
+    ]);
+    let mirror = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::Ram),
+        (0x8000..=0xFFFF, Region::Mirror(0x0100)),
+    ]);
+
+    assert_eq!(
+        rom.err(),
+        Some(MapError::RomSize {
+            Start: 0x8000,
+            End: 0xFFFF,
+            Size: 0x4000,
+        })
+    );
+    assert_eq!(
+        mirror.err(),
+        Some(MapError::MirrorTarget {
+            Start: 0x8000,
+            Target: 0x0100,
+        })
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_REJECTS_MIRRORS_OF_OPEN_BUS() {
+    let map = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::OpenBus),
+        (0x8000..=0xFFFF, Region::Mirror(0x0000)),
+    ]);
+
+    assert_eq!(
+        map.err(),
+        Some(MapError::MirrorTarget {
+            Start: 0x8000,
+            Target: 0x0000,
+        })
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MEMORY_MAP_HANDS_BACK_IO_DEVICES() {
+    let mut map = nes_map(vec![0; 0x8000]);
+
+    map.write(0x2003, 0x42);
+    map.device_mut::<Registers>(0x200C).unwrap().0[5] = 0x24;
+
+    assert_eq!(map.device::<Registers>(0x2000).unwrap().0[3], 0x42);
+    assert_eq!(map.read(0x2005), 0x24);
+    // wrong type, or no device there at all
+    assert!(map.device::<Memory>(0x2000).is_none());
+    assert!(map.device::<Registers>(0x0000).is_none());
+}
+
+// Mappers
+
+// Every bank starts with its own number
+fn numbered_banks(banks: usize, size: usize) -> Vec<Byte> {
+    let mut rom = vec![0; banks * size];
+    for bank in 0..banks {
+        rom[bank * size] = bank as Byte;
+    }
+    rom
+}
+
+fn nes_cartridge_map(cartridge: Region) -> MemoryMap {
+    MemoryMap::new(vec![
+        (0x0000..=0x5FFF, Region::Ram),
+        (0x6000..=0xFFFF, cartridge),
+    ])
+    .unwrap()
+}
+
+// A store from a program running in PRG ROM, whose opcode fetch keeps it apart from
+// the previous write
+fn mmc1_write(map: &mut MemoryMap, address: Word, value: Byte) {
+    map.read(0x8000);
+    map.write(address, value);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn NROM_MIRRORS_16KB_IMAGES() {
+    let mut rom = vec![0; 0x4000];
+    rom[0x0010] = 0x42;
+    let cartridge = Cartridge::new(Nrom, rom, 0);
+    let mut map = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::Ram),
+        (0x8000..=0xFFFF, Region::Io(Box::new(cartridge))),
+    ])
+    .unwrap();
+
+    map.write(0x8010, 0x99);
+
+    assert_eq!(map.read(0x8010), 0x42);
+    assert_eq!(map.read(0xC010), 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn UXROM_SWITCHES_THE_LOWER_BANK() {
+    let rom = numbered_banks(8, 0x4000);
+    let cartridge = Cartridge::new(UxRom::new(8), rom, 0);
+    let mut map = MemoryMap::new(vec![
+        (0x0000..=0x7FFF, Region::Ram),
+        (0x8000..=0xFFFF, Region::Io(Box::new(cartridge))),
+    ])
+    .unwrap();
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    assert_eq!(map.read(0x8000), 0);
+    assert_eq!(map.read(0xC000), 7);
+
+    cpu.PC = 0x0200;
+    cpu.A = 0x03;
+    map.write(0x0200, instructions::STA::ABS);
+    map.write(0x0201, 0x00);
+    map.write(0x0202, 0x80);
+    cpu.execute(&mut map).unwrap();
+
+    assert_eq!(map.read(0x8000), 3);
+    assert_eq!(map.read(0xC000), 7);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MMC1_LOADS_REGISTERS_A_BIT_AT_A_TIME() {
+    let rom = numbered_banks(16, 0x4000);
+    let cartridge = Cartridge::new(Mmc1::new(16), rom, 0x2000);
+    let mut map = nes_cartridge_map(Region::Io(Box::new(cartridge)));
+
+    // PRG bank 5, low bit first
+    for bit in [1, 0, 1, 0, 0] {
+        mmc1_write(&mut map, 0xE000, bit);
+    }
+
+    assert_eq!(map.read(0x8000), 5);
+    assert_eq!(map.read(0xC000), 15);
+
+    // control: first bank fixed at $8000, switching at $C000
+    for bit in [0, 0, 0, 1, 0] {
+        mmc1_write(&mut map, 0x8000, bit);
+    }
+
+    assert_eq!(map.read(0x8000), 0);
+    assert_eq!(map.read(0xC000), 5);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MMC1_TAKES_ONE_BIT_FROM_A_READ_MODIFY_WRITE() {
+    let rom = numbered_banks(16, 0x4000);
+    let cartridge = Cartridge::new(Mmc1::new(16), rom, 0x2000);
+    let mut map = nes_cartridge_map(Region::Io(Box::new(cartridge)));
+    let mut cpu = CPU::new();
+    cpu.reset();
+
+    // PRG bank 5: ASL $FFFF writes the zero it reads twice, only the first counts
+    cpu.PC = 0x0200;
+    map.write(0x0200, instructions::ASL::ABS);
+    map.write(0x0201, 0xFF);
+    map.write(0x0202, 0xFF);
+
+    mmc1_write(&mut map, 0xE000, 1);
+    cpu.execute(&mut map).unwrap();
+    for bit in [1, 0, 0] {
+        mmc1_write(&mut map, 0xE000, bit);
+    }
+
+    assert_eq!(map.read(0x8000), 5);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MMC1_RESET_RESTARTS_THE_SEQUENCE() {
+    let rom = numbered_banks(16, 0x4000);
+    let cartridge = Cartridge::new(Mmc1::new(16), rom, 0x2000);
+    let mut map = nes_cartridge_map(Region::Io(Box::new(cartridge)));
+
+    mmc1_write(&mut map, 0xE000, 1);
+    mmc1_write(&mut map, 0xE000, 1);
+    mmc1_write(&mut map, 0x8000, 0x80);
+    for bit in [0, 1, 0, 0, 0] {
+        mmc1_write(&mut map, 0xE000, bit);
+    }
+    map.write(0x6123, 0x42);
+
+    assert_eq!(map.read(0x8000), 2);
+    assert_eq!(map.read(0x6123), 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MMC1_CHR_BANKS_CAN_BE_READ_BACK_FROM_THE_MAP() {
+    let rom = numbered_banks(16, 0x4000);
+    let cartridge = Cartridge::new(Mmc1::new(16), rom, 0x2000);
+    let mut map = nes_cartridge_map(Region::Io(Box::new(cartridge)));
+
+    // CHR bank 0 = 6, CHR bank 1 = 9
+    for bit in [0, 1, 1, 0, 0] {
+        mmc1_write(&mut map, 0xA000, bit);
+    }
+    for bit in [1, 0, 0, 1, 0] {
+        mmc1_write(&mut map, 0xC000, bit);
+    }
+
+    let mmc1 = map.device::<Cartridge<Mmc1>>(0x8000).unwrap().mapper();
+    assert_eq!(mmc1.ChrBank0, 6);
+    assert_eq!(mmc1.ChrBank1, 9);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn MMC1_REGISTER_SELECT_WRAPS_PAST_THE_WINDOW() {
+    let mut mmc1 = Mmc1::new(8);
+
+    // offsets past $9FFF don't exist in the $6000 window but must not overflow
+    for _ in 0..5 {
+        mmc1.access(0x2000, None);
+        mmc1.access(0xFFFF, Some(0x01));
+    }
+    for _ in 0..5 {
+        mmc1.access(0x2000, None);
+        mmc1.access(0xA000, Some(0x00));
+    }
+
+    assert_eq!(mmc1.ChrBank1, 0x1F);
+    assert_eq!(mmc1.Control, 0x00);
+}
+
+#[allow(non_snake_case)]
+#[test]
+#[should_panic(expected = "UxROM needs at least one PRG bank")]
+fn UXROM_REFUSES_ZERO_BANKS() {
+    UxRom::new(0);
+}
+
+#[allow(non_snake_case)]
+#[test]
+#[should_panic(expected = "MMC1 needs at least one PRG bank")]
+fn MMC1_REFUSES_ZERO_BANKS() {
+    Mmc1::new(0);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ATARI_F8_SWITCHES_ON_HOTSPOT_READS() {
+    let mut rom = vec![0; 0x2000];
+    // bank 1, the one selected at power-up
+    rom[0x1000] = instructions::LDA::ABS;
+    rom[0x1001] = 0xF8;
+    rom[0x1002] = 0xFF;
+    rom[0x1003] = instructions::LDX::IMM;
+    rom[0x1004] = 0x24;
+    // bank 0
+    rom[0x0003] = instructions::LDX::IMM;
+    rom[0x0004] = 0x42;
+    let cartridge = Cartridge::new(Atari::f8(), rom, 0);
+    let mut map = MemoryMap::new(vec![
+        (0x0000..=0x0FFF, Region::Ram),
+        (0x1000..=0x1FFF, Region::Io(Box::new(cartridge))),
+        (0x2000..=0xFFFF, Region::OpenBus),
+    ])
+    .unwrap();
+    let mut cpu = CPU::with_model(CpuModel::Mos6507);
+    cpu.reset();
+
+    cpu.PC = 0xF000;
+    cpu.execute(&mut map).unwrap();
+    cpu.execute(&mut map).unwrap();
+
+    assert_eq!(cpu.X, 0x42);
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ATARI_F6_HAS_FOUR_HOTSPOTS() {
+    let rom = numbered_banks(4, 0x1000);
+    let cartridge = Cartridge::new(Atari::f6(), rom, 0);
+    let mut map = MemoryMap::new(vec![
+        (0x0000..=0x0FFF, Region::Ram),
+        (0x1000..=0x1FFF, Region::Io(Box::new(cartridge))),
+        (0x2000..=0xFFFF, Region::OpenBus),
+    ])
+    .unwrap();
+
+    assert_eq!(map.read(0x1000), 3);
+    map.read(0x1FF7);
+    assert_eq!(map.read(0x1000), 1);
+    map.write(0x1FF6, 0x00);
+    assert_eq!(map.read(0x1000), 0);
+    map.read(0x1FF9);
+    assert_eq!(map.read(0x1000), 3);
+}